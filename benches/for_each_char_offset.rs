@@ -0,0 +1,39 @@
+use std::hint::black_box;
+
+use divan::Bencher;
+use string_view::StrExt;
+
+fn main() {
+    divan::main();
+}
+
+// Sum byte offsets of every character inside a view
+
+fn input() -> String {
+    "Hello, Мир! 本😀 ".repeat(500)
+}
+
+#[divan::bench(sample_count = 10_000)]
+fn char_indices_in_place(b: Bencher) {
+    let input = input();
+    let view = input.view();
+
+    b.bench_local(|| {
+        let mut sum = 0;
+        view.char_indices_in_place()
+            .for_each(|(offset, ch)| sum += offset + ch.len_utf8());
+        black_box(sum)
+    });
+}
+
+#[divan::bench(sample_count = 10_000)]
+fn for_each_char_offset(b: Bencher) {
+    let input = input();
+    let view = input.view();
+
+    b.bench_local(|| {
+        let mut sum = 0;
+        view.for_each_char_offset(|offset, ch| sum += offset + ch.len_utf8());
+        black_box(sum)
+    });
+}
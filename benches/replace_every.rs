@@ -16,12 +16,11 @@ fn alphabet_std_push(b: Bencher) {
     let mut input = String::from(ALP);
 
     b.bench_local(|| {
-        
         let mut out = String::with_capacity(ALP.len());
 
         for (idx, _) in input.char_indices() {
             let next = &ALP.get(idx + 1..idx + 2).unwrap_or("a");
-            out.push_str(&next);
+            out.push_str(next);
         }
 
         input = out;
@@ -35,7 +34,6 @@ fn alphabet_string_view_in_place(b: Bencher) {
     let mut input = String::from(ALP);
 
     b.bench_local(|| {
-
         for (idx, mut ch) in input.chars_in_place_mut().enumerate() {
             let next = &ALP.get(idx + 1..idx + 2).unwrap_or("a");
             ch.replace(next.chars().next().unwrap()).unwrap();
@@ -43,4 +41,4 @@ fn alphabet_string_view_in_place(b: Bencher) {
 
         black_box(&mut input);
     });
-}
\ No newline at end of file
+}
@@ -0,0 +1,44 @@
+use std::hint::black_box;
+
+use divan::Bencher;
+use string_view::StrExt;
+
+fn main() {
+    divan::main();
+}
+
+const TEXT: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor
+incididunt ut labore et dolore magna aliqua.    Ut enim ad minim veniam, quis nostrud exercitation
+ullamco laboris nisi ut aliquip ex ea commodo consequat.\tDuis aute irure dolor in reprehenderit in
+voluptate velit esse cillum dolore eu fugiat nulla pariatur.  Excepteur sint occaecat cupidatat non
+proident, sunt in culpa qui officia deserunt mollit anim id est laborum.";
+
+#[divan::bench(sample_count = 10_000)]
+fn split_whitespace_std(b: Bencher) {
+    let input = String::from(TEXT);
+    b.bench_local(|| {
+        for word in input.split_whitespace() {
+            black_box(word);
+        }
+    });
+}
+
+#[divan::bench(sample_count = 10_000)]
+fn split_ascii_whitespace_std(b: Bencher) {
+    let input = String::from(TEXT);
+    b.bench_local(|| {
+        for word in input.split_ascii_whitespace() {
+            black_box(word);
+        }
+    });
+}
+
+#[divan::bench(sample_count = 10_000)]
+fn split_ascii_whitespace_in_place(b: Bencher) {
+    let input = String::from(TEXT);
+    b.bench_local(|| {
+        for word in input.split_ascii_whitespace_in_place() {
+            black_box(word);
+        }
+    });
+}
@@ -1,6 +1,9 @@
+use core::cmp::Ordering;
 use core::error::Error;
 use core::fmt::{Debug, Display};
+use core::hash::{Hash, Hasher};
 use core::slice;
+use core::str::Bytes;
 
 /// In-place character representation inside string slice.
 ///
@@ -16,6 +19,16 @@ use core::slice;
 /// assert_eq!(ch, "l");
 /// ```
 ///
+/// Characters are compared by their contents, not by their location inside base string.
+/// [`Char`] can also be compared with [`CharMut`]. Ordering of [`Char`]s matches ordering
+/// of `char`s, so they can be used as keys in ordered and hashed collections.
+///
+/// ```rust
+/// use string_view::Char;
+///
+/// assert_eq!(Char::new(&"Hello"[2..3]), Char::new(&"World"[3..4]));
+/// ```
+///
 /// ```rust,should_panic
 /// use string_view::Char;
 ///
@@ -27,12 +40,12 @@ use core::slice;
 #[derive(PartialEq, Eq)]
 pub struct Char<'a>(&'a str);
 
-impl Char<'_> {
+impl<'a> Char<'a> {
     /// Creates new `Char` from single-character string slice. This character can take
     /// from 1 to 4 bytes inside string slice.
     ///
     /// **Panics** if argument is not single-character string slice.
-    pub fn new(ch: &str) -> Char<'_> {
+    pub fn new(ch: &'a str) -> Char<'a> {
         let char_len = ch
             .chars()
             .next()
@@ -54,9 +67,71 @@ impl Char<'_> {
         unsafe { self.as_str().chars().next().unwrap_unchecked() }
     }
 
-    pub fn as_str(&self) -> &str {
+    pub fn as_str(&self) -> &'a str {
         self.0
     }
+
+    /// Returns `UTF-8` bytes of this character. Character takes from 1 to 4 bytes.
+    ///
+    /// ```rust
+    /// use string_view::Char;
+    ///
+    /// assert_eq!(Char::new("€").as_bytes(), [0xE2, 0x82, 0xAC]);
+    /// ```
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0.as_bytes()
+    }
+
+    /// Returns iterator over `UTF-8` bytes of this character.
+    ///
+    /// ```rust
+    /// use string_view::Char;
+    ///
+    /// let mut bytes = Char::new("é").bytes();
+    ///
+    /// assert_eq!(bytes.next(), Some(0xC3));
+    /// assert_eq!(bytes.next(), Some(0xA9));
+    /// assert_eq!(bytes.next(), None);
+    /// ```
+    pub fn bytes(&self) -> Bytes<'a> {
+        self.0.bytes()
+    }
+
+    /// Checks that this character is an ASCII case-insensitive match with `other`.
+    ///
+    /// ```rust
+    /// use string_view::Char;
+    ///
+    /// assert!(Char::new("a").eq_ignore_ascii_case('A'));
+    /// assert!(!Char::new("ж").eq_ignore_ascii_case('Ж'));
+    /// ```
+    pub fn eq_ignore_ascii_case(&self, other: char) -> bool {
+        self.char().eq_ignore_ascii_case(&other)
+    }
+
+    /// Number of bytes this character takes in `UTF-8`.
+    ///
+    /// ```rust
+    /// use string_view::Char;
+    ///
+    /// assert_eq!(Char::new("a").len_utf8(), 1);
+    /// assert_eq!(Char::new("€").len_utf8(), 3);
+    /// ```
+    pub fn len_utf8(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks if this character is within the ASCII range.
+    ///
+    /// ```rust
+    /// use string_view::Char;
+    ///
+    /// assert!(Char::new("a").is_ascii());
+    /// assert!(!Char::new("ж").is_ascii());
+    /// ```
+    pub fn is_ascii(&self) -> bool {
+        self.0.is_ascii()
+    }
 }
 
 impl Debug for Char<'_> {
@@ -71,6 +146,41 @@ impl Display for Char<'_> {
     }
 }
 
+impl Hash for Char<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.char().hash(state);
+    }
+}
+
+impl PartialOrd for Char<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Char<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // UTF-8 byte order is the same as order of code points
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+/// Serializes character as a single-character string. Requires `serde` feature.
+///
+/// Like views, [`Char`] only borrows its contents, so it doesn't implement `Deserialize`.
+///
+/// ```rust
+/// use string_view::Char;
+///
+/// assert_eq!(serde_json::to_string(&Char::new("ж")).unwrap(), r#""ж""#);
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for Char<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 impl PartialEq<char> for Char<'_> {
     fn eq(&self, other: &char) -> bool {
         self.char() == *other
@@ -101,25 +211,35 @@ impl PartialEq<&str> for Char<'_> {
 ///
 /// See [`CharsInPlaceMut`] for mutable version.
 /// See [`StrExt::chars_in_place`](crate::StrExt::chars_in_place) for method syntax.
+#[derive(Clone, Copy)]
 pub struct CharsInPlace<'a>(&'a str);
 
 impl<'a> CharsInPlace<'a> {
     pub fn new(s: &'a str) -> Self {
         CharsInPlace(s)
     }
+
+    /// Returns part of string slice which was not yet yielded from either end.
+    ///
+    /// ```rust
+    /// use string_view::CharsInPlace;
+    ///
+    /// let mut chars = CharsInPlace::new("hello");
+    ///
+    /// chars.next();
+    /// chars.next_back();
+    /// assert_eq!(chars.remaining(), "ell");
+    /// ```
+    pub fn remaining(&self) -> &'a str {
+        self.0
+    }
 }
 
 impl<'a> Iterator for CharsInPlace<'a> {
     type Item = Char<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-
-        let next_char_len = match self.0.as_bytes().first()? {
-            ..=0b0111_1111 => 1,
-            ..=0b1011_1111 => 2,
-            ..=0b1101_1111 => 3,
-            _ => 4,
-        };
+        let next_char_len = utf8_char_len(*self.0.as_bytes().first()?);
 
         // SAFETY: next_char_len is guaranteed to be on a char boundry
         let (this, rest) = unsafe { str_split_at_unchecked(self.0, next_char_len) };
@@ -127,11 +247,30 @@ impl<'a> Iterator for CharsInPlace<'a> {
 
         Some(Char(this))
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let bytes = self.0.as_bytes();
+
+        let mut skipped_len = 0;
+        for _ in 0..n {
+            match bytes.get(skipped_len) {
+                Some(&byte) => skipped_len += utf8_char_len(byte),
+                None => {
+                    self.0 = "";
+                    return None;
+                }
+            }
+        }
+
+        // SAFETY: skipped_len is a sum of char lengths so it's on a char boundry
+        self.0 = unsafe { str_split_at_unchecked(self.0, skipped_len).1 };
+        self.next()
+    }
 }
 
 impl<'a> DoubleEndedIterator for CharsInPlace<'a> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        let next_char_len = self.0.chars().rev().next()?.len_utf8();
+        let next_char_len = self.0.chars().next_back()?.len_utf8();
 
         // SAFETY: next_char_len is guaranteed to be on a char boundry as it is returned from len_utf8
         // This function is performance critical so it's ok to use unsafe
@@ -142,6 +281,67 @@ impl<'a> DoubleEndedIterator for CharsInPlace<'a> {
     }
 }
 
+/// Immutable iterator of chars in-place with their byte offsets.
+///
+/// Offsets are relative to the start of the base string slice, so they stay correct
+/// when iterating in reverse with [`.rev()`](Iterator::rev).
+///
+/// ```rust
+/// use string_view::StrExt;
+///
+/// let text = "aé本";
+/// let mut chars = text.view().char_indices_in_place();
+///
+/// let (idx, ch) = chars.next().unwrap();
+/// assert_eq!((idx, ch.as_str()), (0, "a"));
+///
+/// let (idx, ch) = chars.next_back().unwrap();
+/// assert_eq!((idx, ch.as_str()), (3, "本"));
+///
+/// let (idx, ch) = chars.next().unwrap();
+/// assert_eq!((idx, ch.as_str()), (1, "é"));
+///
+/// assert!(chars.next().is_none());
+/// ```
+///
+/// See [`StringView::char_indices_in_place`](crate::StringView::char_indices_in_place).
+pub struct CharIndicesInPlace<'a> {
+    offset: usize,
+    chars: CharsInPlace<'a>,
+}
+
+impl<'a> CharIndicesInPlace<'a> {
+    /// Creates iterator over `s` where first character is located at byte `offset`
+    /// inside base string slice.
+    pub fn new(s: &'a str, offset: usize) -> Self {
+        CharIndicesInPlace {
+            offset,
+            chars: CharsInPlace(s),
+        }
+    }
+}
+
+impl<'a> Iterator for CharIndicesInPlace<'a> {
+    type Item = (usize, Char<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ch = self.chars.next()?;
+        let idx = self.offset;
+        self.offset += ch.as_str().len();
+
+        Some((idx, ch))
+    }
+}
+
+impl<'a> DoubleEndedIterator for CharIndicesInPlace<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let ch = self.chars.next_back()?;
+
+        // Remaining chars are located between `offset` and this char
+        Some((self.offset + self.chars.0.len(), ch))
+    }
+}
+
 /// In-place character representation inside mutable str slice
 ///
 /// Convert to [`Char`] using [`CharMut::as_char`].
@@ -193,6 +393,46 @@ impl CharMut<'_> {
         self.0
     }
 
+    /// Returns `UTF-8` bytes of this character.
+    ///
+    /// ```rust
+    /// use string_view::CharMut;
+    ///
+    /// let mut text = String::from("€");
+    /// let ch = CharMut::new(&mut text);
+    ///
+    /// assert_eq!(ch.as_bytes(), [0xE2, 0x82, 0xAC]);
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    /// Returns iterator over `UTF-8` bytes of this character.
+    pub fn bytes(&self) -> Bytes<'_> {
+        self.0.bytes()
+    }
+
+    /// Number of bytes this character takes in `UTF-8`.
+    ///
+    /// Replacement with [`CharMut::replace`] is only possible for character of the same length.
+    ///
+    /// ```rust
+    /// use string_view::CharMut;
+    ///
+    /// let mut text = String::from("ж");
+    /// let ch = CharMut::new(&mut text);
+    ///
+    /// assert_eq!(ch.len_utf8(), 2);
+    /// ```
+    pub fn len_utf8(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Checks if this character is within the ASCII range.
+    pub fn is_ascii(&self) -> bool {
+        self.0.is_ascii()
+    }
+
     /// Get underlaying mutable string slice.
     ///
     /// This function generally **shouldn't be used** as you are able to mutate this slice
@@ -224,7 +464,10 @@ impl CharMut<'_> {
     /// Checks at runtime if chars have the same length in `UTF-8` and returns an error if they don't.
     pub fn replace(&mut self, ch: char) -> Result<(), CharsHaveDifferentSizes> {
         if !self.is_same_size(ch) {
-            return Err(CharsHaveDifferentSizes);
+            return Err(CharsHaveDifferentSizes {
+                expected: self.0.len(),
+                found: ch.len_utf8(),
+            });
         }
         let mut buf: [u8; 4] = [0; 4];
         let subslice: &[u8] = ch.encode_utf8(&mut buf).as_bytes();
@@ -238,9 +481,109 @@ impl CharMut<'_> {
         Ok(())
     }
 
+    /// Replace character in-place with string slice of the same length in bytes.
+    ///
+    /// returns [`Err`] if `rep` has different length.
+    ///
+    /// `rep` can contain multiple characters, in which case [`CharMut`] no longer points to
+    /// single character and [`.char()`](CharMut::char) returns the first one.
+    /// See [`CharMut::as_str_mut`] for details.
+    ///
+    /// ```rust
+    /// # extern crate std;
+    /// # use std::string::String;
+    /// use string_view::StrExt;
+    ///
+    /// let text: &mut str = &mut String::from("aПb");
+    /// let mut ch = text.chars_in_place_mut().nth(1).unwrap();
+    ///
+    /// assert!(ch.replace_str("P").is_err());
+    /// assert!(ch.replace_str("Pi").is_ok());
+    /// assert_eq!(ch.char(), 'P');
+    ///
+    /// assert_eq!(text, "aPib");
+    /// ```
+    pub fn replace_str(&mut self, rep: &str) -> Result<(), CharsHaveDifferentSizes> {
+        if self.0.len() != rep.len() {
+            return Err(CharsHaveDifferentSizes {
+                expected: self.0.len(),
+                found: rep.len(),
+            });
+        }
+        // Safety: rep is a valid string slice with the same number of bytes
+        unsafe {
+            self.0.as_bytes_mut().copy_from_slice(rep.as_bytes());
+        }
+        Ok(())
+    }
+
+    /// Replace character in-place with ASCII character `byte` without encoding it.
+    ///
+    /// returns [`Err`] if current character is not 1 byte long or `byte` is not ASCII.
+    /// Non-ASCII `byte` is not a character on its own, so it is reported with `found` equal to `0`.
+    ///
+    /// ```rust
+    /// # extern crate std;
+    /// # use std::string::String;
+    /// use string_view::StrExt;
+    ///
+    /// let text: &mut str = &mut String::from("aПb");
+    /// let mut chars = text.chars_in_place_mut();
+    ///
+    /// assert!(chars.next().unwrap().set_ascii(b'X').is_ok());
+    /// assert!(chars.next().unwrap().set_ascii(b'Y').is_err());
+    ///
+    /// let err = chars.next().unwrap().set_ascii(0xE9).unwrap_err();
+    /// assert_eq!((err.expected, err.found), (1, 0));
+    ///
+    /// assert_eq!(text, "XПb");
+    /// ```
+    pub fn set_ascii(&mut self, byte: u8) -> Result<(), CharsHaveDifferentSizes> {
+        if !byte.is_ascii() {
+            return Err(CharsHaveDifferentSizes {
+                expected: self.0.len(),
+                found: 0,
+            });
+        }
+        // Safety: single ASCII byte is replaced with another ASCII byte
+        match unsafe { self.0.as_bytes_mut() } {
+            [current] => {
+                *current = byte;
+                Ok(())
+            }
+            bytes => Err(CharsHaveDifferentSizes {
+                expected: bytes.len(),
+                found: 1,
+            }),
+        }
+    }
+
+    /// Replace character in-place with the one returned by `func` called with current character.
+    ///
+    /// Checks at runtime if chars have the same length in `UTF-8` and returns an error if they don't.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("abc");
+    ///
+    /// for mut ch in text.chars_in_place_mut() {
+    ///     ch.replace_with(|ch| (ch as u8 + 1) as char).unwrap();
+    /// }
+    /// assert_eq!(text, "bcd");
+    /// ```
+    pub fn replace_with<F>(&mut self, func: F) -> Result<(), CharsHaveDifferentSizes>
+    where
+        F: FnOnce(char) -> char,
+    {
+        self.replace(func(self.char()))
+    }
+
     /// Makes [`CharMut`] uppercase in-place.
     ///
-    /// returns [`Err`] if uppercase variant has different size.
+    /// returns [`Err`] if uppercase variant has different size. Uppercase variant can
+    /// consist of multiple characters, e.g. 'ß' is uppercased to "SS", in which case
+    /// `found` is their total size and can be equal to `expected`.
     ///
     /// ```rust
     /// # extern crate std;
@@ -258,14 +601,19 @@ impl CharMut<'_> {
         let this_upper = upper_chars.next().unwrap();
 
         if upper_chars.next().is_some() {
-            return Err(CharsHaveDifferentSizes);
+            return Err(CharsHaveDifferentSizes {
+                expected: self.0.len(),
+                found: this_char.to_uppercase().map(char::len_utf8).sum(),
+            });
         };
         self.replace(this_upper)
     }
 
-    /// Makes [`CharMut`] uppercase in-place.
+    /// Makes [`CharMut`] lowercase in-place.
     ///
-    /// returns [`Err`] if uppercase variant has different size.
+    /// returns [`Err`] if lowercase variant has different size. Lowercase variant can
+    /// consist of multiple characters, e.g. 'İ' is lowercased to "i̇", in which case
+    /// `found` is their total size.
     ///
     /// ```rust
     /// # extern crate std;
@@ -284,10 +632,36 @@ impl CharMut<'_> {
         let this_lower = lower_chars.next().unwrap();
 
         if lower_chars.next().is_some() {
-            return Err(CharsHaveDifferentSizes);
+            return Err(CharsHaveDifferentSizes {
+                expected: self.0.len(),
+                found: this_char.to_lowercase().map(char::len_utf8).sum(),
+            });
         };
         self.replace(this_lower)
     }
+
+    /// Toggles case of [`CharMut`] in-place if it is an ASCII letter.
+    /// Other characters are left untouched.
+    ///
+    /// ```rust
+    /// # extern crate std;
+    /// # use std::string::String;
+    /// use string_view::StrExt;
+    ///
+    /// let text: &mut str = &mut String::from("Hello Мир");
+    /// text.chars_in_place_mut().for_each(|mut ch| ch.toggle_ascii_case());
+    ///
+    /// assert_eq!(text, "hELLO Мир");
+    /// ```
+    pub fn toggle_ascii_case(&mut self) {
+        // Safety: ASCII letters stay ASCII letters, other bytes are not modified
+        let bytes = unsafe { self.as_str_mut().as_bytes_mut() };
+        if let [byte] = bytes {
+            if byte.is_ascii_alphabetic() {
+                *byte ^= 0b0010_0000;
+            }
+        }
+    }
 }
 
 impl Debug for CharMut<'_> {
@@ -314,6 +688,18 @@ impl PartialEq<&str> for CharMut<'_> {
     }
 }
 
+impl PartialEq<Char<'_>> for CharMut<'_> {
+    fn eq(&self, other: &Char<'_>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl PartialEq<CharMut<'_>> for Char<'_> {
+    fn eq(&self, other: &CharMut<'_>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
 /// Mutable iterator of chars in-place
 ///
 /// See [`CharsInPlace`] for immutable version
@@ -329,13 +715,7 @@ impl<'a> Iterator for CharsInPlaceMut<'a> {
     type Item = CharMut<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-
-        let next_char_len = match self.0.as_bytes().first()? {
-            ..=0b0111_1111 => 1,
-            ..=0b1101_1111 => 2,
-            ..=0b1110_1111 => 3,
-            _ => 4,
-        };
+        let next_char_len = utf8_char_len(*self.0.as_bytes().first()?);
 
         let this: &mut str = core::mem::take(&mut self.0);
 
@@ -350,7 +730,7 @@ impl<'a> Iterator for CharsInPlaceMut<'a> {
 
 impl<'a> DoubleEndedIterator for CharsInPlaceMut<'a> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        let next_char_len = self.0.chars().rev().next()?.len_utf8();
+        let next_char_len = self.0.chars().next_back()?.len_utf8();
 
         let this: &mut str = core::mem::take(&mut self.0);
 
@@ -365,14 +745,34 @@ impl<'a> DoubleEndedIterator for CharsInPlaceMut<'a> {
 }
 
 /// Common error case while working with chars in-place
-pub struct CharsHaveDifferentSizes;
+///
+/// ```rust
+/// use string_view::StrExt;
+///
+/// let mut text = String::from("Hello");
+/// let mut ch = text.chars_in_place_mut().next().unwrap();
+///
+/// let err = ch.replace('П').unwrap_err();
+/// assert_eq!(err.expected, 1);
+/// assert_eq!(err.found, 2);
+/// ```
+pub struct CharsHaveDifferentSizes {
+    /// Size of original character in bytes.
+    pub expected: usize,
+    /// Size of replacement in bytes.
+    pub found: usize,
+}
 
 impl Debug for CharsHaveDifferentSizes {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
-            "Unable to replace character because they have different sizes.\
-            Characters have to have the same size for in-place modification."
+            "Unable to replace character because they have different sizes: \
+            expected {} byte{}, got {}. \
+            Characters have to have the same size for in-place modification.",
+            self.expected,
+            if self.expected == 1 { "" } else { "s" },
+            self.found,
         )
     }
 }
@@ -385,6 +785,43 @@ impl Display for CharsHaveDifferentSizes {
 
 impl Error for CharsHaveDifferentSizes {}
 
+/// The only error case in [`StrExt::try_replace_in_place`](crate::StrExt::try_replace_in_place).
+pub struct ReplaceError {
+    /// Length of original string slice in bytes.
+    pub expected: usize,
+    /// Length of replacement string slice in bytes.
+    pub found: usize,
+}
+
+impl Debug for ReplaceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Replacement string slice has to have the same size as the original: \
+            expected {} bytes, got {}. Consider creating mutable subslice with different length",
+            self.expected, self.found,
+        )
+    }
+}
+
+impl Display for ReplaceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self, f)
+    }
+}
+
+impl Error for ReplaceError {}
+
+/// Length of `UTF-8` encoded character in bytes determined by its first byte.
+const fn utf8_char_len(first_byte: u8) -> usize {
+    match first_byte {
+        0b0000_0000..=0b0111_1111 => 1,
+        0b1000_0000..=0b1101_1111 => 2,
+        0b1110_0000..=0b1110_1111 => 3,
+        _ => 4,
+    }
+}
+
 /// pub version of [`str::split_at_unchecked`].
 ///
 /// # Safety
@@ -0,0 +1,462 @@
+use crate::StringView;
+
+/// Iterator over overlapping string slices of `n` consecutive characters.
+///
+/// ```rust
+/// use string_view::WindowsChars;
+///
+/// let mut windows = WindowsChars::new("Привет", 5);
+///
+/// assert_eq!(windows.next(), Some("Приве"));
+/// assert_eq!(windows.next(), Some("ривет"));
+/// assert_eq!(windows.next(), None);
+/// ```
+///
+/// See [`StrExt::windows_chars`](crate::StrExt::windows_chars) for method syntax.
+pub struct WindowsChars<'a> {
+    rest: &'a str,
+    /// Byte length of the next window inside `rest`. `None` if there are no windows left.
+    window_len: Option<usize>,
+}
+
+impl<'a> WindowsChars<'a> {
+    /// **Panics** if `n` is zero.
+    pub fn new(s: &'a str, n: usize) -> Self {
+        assert!(n != 0, "Window size cannot be zero");
+
+        let mut chars = s.chars();
+        let mut window_len = Some(0);
+        for _ in 0..n {
+            window_len = window_len
+                .zip(chars.next())
+                .map(|(len, ch)| len + ch.len_utf8());
+        }
+
+        WindowsChars {
+            rest: s,
+            window_len,
+        }
+    }
+}
+
+impl<'a> Iterator for WindowsChars<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let window_len = self.window_len?;
+        let window = &self.rest[..window_len];
+
+        // Window is never empty as `n` is not zero
+        let first_char_len = window.chars().next().map_or(0, char::len_utf8);
+
+        self.window_len = self.rest[window_len..]
+            .chars()
+            .next()
+            .map(|ch| window_len - first_char_len + ch.len_utf8());
+        self.rest = &self.rest[first_char_len..];
+
+        Some(window)
+    }
+}
+
+/// Iterator over words of a string slice separated by ASCII whitespace.
+///
+/// ```rust
+/// use string_view::SplitAsciiWhitespace;
+///
+/// let mut words = SplitAsciiWhitespace::new("  Hello\r\nWorld ");
+///
+/// assert_eq!(words.next(), Some("Hello"));
+/// assert_eq!(words.next(), Some("World"));
+/// assert_eq!(words.next(), None);
+/// ```
+///
+/// See [`StrExt::split_ascii_whitespace_in_place`](crate::StrExt::split_ascii_whitespace_in_place)
+/// for method syntax.
+pub struct SplitAsciiWhitespace<'a>(&'a str);
+
+impl<'a> SplitAsciiWhitespace<'a> {
+    pub fn new(s: &'a str) -> Self {
+        SplitAsciiWhitespace(s)
+    }
+}
+
+impl<'a> Iterator for SplitAsciiWhitespace<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = self.0.as_bytes();
+
+        let Some(start) = bytes.iter().position(|byte| !byte.is_ascii_whitespace())
+        else {
+            self.0 = "";
+            return None;
+        };
+        let end = bytes[start..]
+            .iter()
+            .position(u8::is_ascii_whitespace)
+            .map_or(bytes.len(), |len| start + len);
+
+        // SAFETY: ASCII bytes are always on a char boundary
+        let (word, rest) = unsafe {
+            (
+                self.0.get_unchecked(start..end),
+                self.0.get_unchecked(end..),
+            )
+        };
+        self.0 = rest;
+
+        Some(word)
+    }
+}
+
+/// Iterator over lines of a string slice with byte indices of their starts.
+///
+/// ```rust
+/// use string_view::LineIndices;
+///
+/// let mut lines = LineIndices::new("Hello\r\nWorld");
+///
+/// assert_eq!(lines.next(), Some((0, "Hello")));
+/// assert_eq!(lines.next(), Some((7, "World")));
+/// assert_eq!(lines.next(), None);
+/// ```
+///
+/// See [`StrExt::line_indices`](crate::StrExt::line_indices) for method syntax.
+pub struct LineIndices<'a> {
+    rest: &'a str,
+    offset: usize,
+}
+
+impl<'a> LineIndices<'a> {
+    pub fn new(s: &'a str) -> Self {
+        LineIndices { rest: s, offset: 0 }
+    }
+}
+
+impl<'a> Iterator for LineIndices<'a> {
+    type Item = (usize, &'a str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let start = self.offset;
+
+        let (line, consumed) = match self.rest.as_bytes().iter().position(|&b| b == b'\n') {
+            Some(idx) => {
+                let line = &self.rest[..idx];
+                (line.strip_suffix('\r').unwrap_or(line), idx + 1)
+            }
+            None => (self.rest, self.rest.len()),
+        };
+        self.rest = &self.rest[consumed..];
+        self.offset += consumed;
+
+        Some((start, line))
+    }
+}
+
+/// Iterator over byte indices of char boundaries of a string slice.
+///
+/// Unlike [`str::char_indices`] also yields length of the string slice.
+///
+/// ```rust
+/// use string_view::CharBoundaries;
+///
+/// let mut boundaries = CharBoundaries::new("");
+///
+/// assert_eq!(boundaries.next(), Some(0));
+/// assert_eq!(boundaries.next(), None);
+/// ```
+///
+/// See [`StrExt::char_boundaries`](crate::StrExt::char_boundaries) for method syntax.
+pub struct CharBoundaries<'a> {
+    s: &'a str,
+    next_idx: Option<usize>,
+}
+
+impl<'a> CharBoundaries<'a> {
+    pub fn new(s: &'a str) -> Self {
+        CharBoundaries {
+            s,
+            next_idx: Some(0),
+        }
+    }
+}
+
+impl Iterator for CharBoundaries<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.next_idx?;
+        self.next_idx = self.s[idx..].chars().next().map(|ch| idx + ch.len_utf8());
+
+        Some(idx)
+    }
+}
+
+/// Iterator over alternating runs of delimiter and non-delimiter characters.
+///
+/// ```rust
+/// use string_view::SplitKeepDelim;
+///
+/// let text = "  Hello World";
+/// let mut runs = SplitKeepDelim::new(text, char::is_whitespace);
+///
+/// assert_eq!(runs.next(), Some("  "));
+/// assert_eq!(runs.next(), Some("Hello"));
+/// assert_eq!(runs.next(), Some(" "));
+/// assert_eq!(runs.next(), Some("World"));
+/// assert_eq!(runs.next(), None);
+/// ```
+///
+/// See [`StrExt::split_keep_delim`](crate::StrExt::split_keep_delim) for method syntax.
+pub struct SplitKeepDelim<'a, P> {
+    rest: &'a str,
+    pred: P,
+}
+
+impl<'a, P: FnMut(char) -> bool> SplitKeepDelim<'a, P> {
+    pub fn new(s: &'a str, pred: P) -> Self {
+        SplitKeepDelim { rest: s, pred }
+    }
+}
+
+impl<'a, P: FnMut(char) -> bool> Iterator for SplitKeepDelim<'a, P> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chars = self.rest.char_indices();
+        let is_delim = (self.pred)(chars.next()?.1);
+
+        let run_len = chars
+            .find(|&(_, ch)| (self.pred)(ch) != is_delim)
+            .map_or(self.rest.len(), |(idx, _)| idx);
+
+        let (run, rest) = self.rest.split_at(run_len);
+        self.rest = rest;
+
+        Some(run)
+    }
+}
+
+/// Iterator over views of lines split at every `\n`.
+///
+/// ```rust
+/// use string_view::StrExt;
+///
+/// let text = "[a\nb]";
+/// let mut lines = text.view_part(1, 4).split_lines();
+///
+/// let line = lines.next().unwrap();
+/// assert_eq!((line.as_str(), line.start()), ("a", 1));
+///
+/// let line = lines.next().unwrap();
+/// assert_eq!((line.as_str(), line.start()), ("b", 3));
+///
+/// assert!(lines.next().is_none());
+/// ```
+///
+/// See [`StringView::split_lines`] for method syntax.
+pub struct SplitLines<'a> {
+    base: &'a str,
+    start: usize,
+    end: usize,
+    finished: bool,
+}
+
+impl<'a> SplitLines<'a> {
+    /// `view_start..view_end` has to be a valid view into `base`.
+    pub(crate) fn new(base: &'a str, view_start: usize, view_end: usize) -> Self {
+        SplitLines {
+            base,
+            start: view_start,
+            end: view_end,
+            finished: false,
+        }
+    }
+}
+
+impl<'a> Iterator for SplitLines<'a> {
+    type Item = StringView<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        let line_end = match self.base[self.start..self.end].find('\n') {
+            Some(idx) => self.start + idx,
+            None => {
+                self.finished = true;
+                self.end
+            }
+        };
+        let line = StringView::new_part(self.base, self.start, line_end);
+        self.start = (line_end + 1).min(self.end);
+
+        Some(line)
+    }
+}
+
+/// Iterator over consecutive views of given character lengths.
+///
+/// ```rust
+/// use string_view::StrExt;
+///
+/// let text = "2023-01";
+/// let mut fields = text.view().split_n_chars(&[4, 1, 2]);
+///
+/// assert_eq!(fields.next().unwrap().as_str(), "2023");
+/// assert_eq!(fields.next().unwrap().as_str(), "-");
+/// assert_eq!(fields.next().unwrap().as_str(), "01");
+/// assert!(fields.next().is_none());
+/// ```
+///
+/// See [`StringView::split_n_chars`] for method syntax.
+pub struct SplitNChars<'a, 's> {
+    base: &'a str,
+    start: usize,
+    end: usize,
+    sizes: core::slice::Iter<'s, usize>,
+}
+
+impl<'a, 's> SplitNChars<'a, 's> {
+    /// `view_start..view_end` has to be a valid view into `base`.
+    pub(crate) fn new(
+        base: &'a str,
+        view_start: usize,
+        view_end: usize,
+        sizes: &'s [usize],
+    ) -> Self {
+        SplitNChars {
+            base,
+            start: view_start,
+            end: view_end,
+            sizes: sizes.iter(),
+        }
+    }
+}
+
+impl<'a> Iterator for SplitNChars<'a, '_> {
+    type Item = StringView<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let &size = self.sizes.next()?;
+
+        let mut field = StringView::new_part(self.base, self.start, self.start);
+        if field.try_extend_right(size).is_err() || field.end() > self.end {
+            self.sizes = [].iter();
+            return None;
+        }
+        self.start = field.end();
+
+        Some(field)
+    }
+}
+
+/// Iterator over views of maximal runs of ASCII digits.
+///
+/// ```rust
+/// use string_view::NumberSpans;
+///
+/// let mut numbers = NumberSpans::new("a12b345");
+///
+/// let number = numbers.next().unwrap();
+/// assert_eq!((number.as_str(), number.start()), ("12", 1));
+///
+/// let number = numbers.next().unwrap();
+/// assert_eq!((number.as_str(), number.start()), ("345", 4));
+///
+/// assert!(numbers.next().is_none());
+/// ```
+///
+/// See [`StrExt::ascii_number_spans`](crate::StrExt::ascii_number_spans) for method syntax.
+pub struct NumberSpans<'a> {
+    base: &'a str,
+    offset: usize,
+}
+
+impl<'a> NumberSpans<'a> {
+    pub fn new(s: &'a str) -> Self {
+        NumberSpans { base: s, offset: 0 }
+    }
+}
+
+impl<'a> Iterator for NumberSpans<'a> {
+    type Item = StringView<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let bytes = &self.base.as_bytes()[self.offset..];
+
+        let Some(start) = bytes.iter().position(u8::is_ascii_digit)
+        else {
+            self.offset = self.base.len();
+            return None;
+        };
+        let end = bytes[start..]
+            .iter()
+            .position(|byte| !byte.is_ascii_digit())
+            .map_or(bytes.len(), |len| start + len);
+
+        let number = StringView::new_part(self.base, self.offset + start, self.offset + end);
+        self.offset += end;
+
+        Some(number)
+    }
+}
+
+/// Iterator over non-overlapping mutable string slices of up to `n` characters each.
+/// The last one can be shorter.
+///
+/// ```rust
+/// use string_view::CharChunksMut;
+///
+/// let mut text = String::from("abcdefg");
+/// let mut chunks = CharChunksMut::new(&mut text, 3);
+///
+/// chunks.next().unwrap().make_ascii_uppercase();
+/// assert_eq!(chunks.next().as_deref(), Some("def"));
+/// assert_eq!(chunks.next().as_deref(), Some("g"));
+/// assert!(chunks.next().is_none());
+///
+/// assert_eq!(text, "ABCdefg");
+/// ```
+///
+/// See [`StrExt::char_chunks_mut`](crate::StrExt::char_chunks_mut) for method syntax.
+pub struct CharChunksMut<'a> {
+    rest: &'a mut str,
+    chunk_size: usize,
+}
+
+impl<'a> CharChunksMut<'a> {
+    /// **Panics** if `n` is zero.
+    pub fn new(s: &'a mut str, n: usize) -> Self {
+        assert!(n != 0, "Chunk size cannot be zero");
+
+        CharChunksMut {
+            rest: s,
+            chunk_size: n,
+        }
+    }
+}
+
+impl<'a> Iterator for CharChunksMut<'a> {
+    type Item = &'a mut str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let chunk_len = self
+            .rest
+            .char_indices()
+            .nth(self.chunk_size)
+            .map_or(self.rest.len(), |(idx, _)| idx);
+
+        let (chunk, rest) = core::mem::take(&mut self.rest).split_at_mut(chunk_len);
+        self.rest = rest;
+
+        Some(chunk)
+    }
+}
@@ -1,12 +1,25 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::iter::Take;
+use core::ops::{Bound, RangeBounds};
+use core::str::Lines;
+
+#[cfg(feature = "width")]
+use unicode_width::UnicodeWidthChar;
+
 mod string_view;
 pub use string_view::*;
 
 mod char;
 pub use crate::char::*;
 
+mod iter;
+pub use iter::*;
+
 #[cfg(test)]
 mod test;
 
@@ -23,6 +36,37 @@ pub trait StrExt {
     /// Returns [`StringViewMut`] of a part of a string slice.
     fn view_part_mut(&mut self, start_idx: usize, end_idx: usize) -> StringViewMut<'_>;
 
+    /// Returns [`StringView`] of a part of a string slice using range of byte indices.
+    ///
+    /// **Panics** if range is out of bounds or does not lie on character boundaries,
+    /// same as [`str`] indexing.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    ///
+    /// assert_eq!(text.view_range(6..11).as_str(), "World");
+    /// assert_eq!(text.view_range(6..).as_str(), "World");
+    /// assert_eq!(text.view_range(..5).as_str(), "Hello");
+    /// ```
+    fn view_range<R: RangeBounds<usize>>(&self, range: R) -> StringView<'_>;
+
+    /// Returns [`StringViewMut`] of a part of a string slice using range of byte indices.
+    ///
+    /// **Panics** if range is out of bounds or does not lie on character boundaries,
+    /// same as [`str`] indexing.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    ///
+    /// text.view_range_mut(6..).as_str_mut().make_ascii_uppercase();
+    /// assert_eq!(text, "Hello WORLD");
+    /// ```
+    fn view_range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> StringViewMut<'_>;
+
     /// Start and end byte indices of this [`Char`] inside base [`str`].
     ///
     /// ```rust
@@ -144,6 +188,25 @@ pub trait StrExt {
     /// ```
     fn replace_in_place(&mut self, rep: &str);
 
+    /// Replaces whole string slice with another one with same length in-place.
+    ///
+    /// returns [`Err`] if replacement string slice has different length.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    ///
+    /// let result = (&mut text[6..11]).try_replace_in_place("WORLD");
+    /// assert!(result.is_ok());
+    /// assert_eq!(text, "Hello WORLD");
+    ///
+    /// let err = (&mut text[6..11]).try_replace_in_place("Rust").unwrap_err();
+    /// assert_eq!((err.expected, err.found), (5, 4));
+    /// assert_eq!(text, "Hello WORLD");
+    /// ```
+    fn try_replace_in_place(&mut self, rep: &str) -> Result<(), ReplaceError>;
+
     /// Replaces all characters in this string slice with provided `char` in-place.
     ///
     /// **Panics** if argument has incompatible [length in `UTF-8` encoding](char::len_utf8).
@@ -204,6 +267,548 @@ pub trait StrExt {
     /// assert_eq!(subslice, "Hello  World");
     /// ```
     fn trim_mut(&mut self) -> &mut str;
+
+    /// Returns a mutable string slice with all prefixes and suffixes equal to `ch` repeatedly removed.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("**text**");
+    ///
+    /// let subslice: &mut str = text.trim_matches_char_mut('*');
+    /// assert_eq!(subslice, "text");
+    /// ```
+    fn trim_matches_char_mut(&mut self, ch: char) -> &mut str;
+
+    /// Returns a mutable string slice with all prefixes equal to `ch` repeatedly removed.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("**text**");
+    ///
+    /// let subslice: &mut str = text.trim_start_matches_char_mut('*');
+    /// assert_eq!(subslice, "text**");
+    /// ```
+    fn trim_start_matches_char_mut(&mut self, ch: char) -> &mut str;
+
+    /// Returns a mutable string slice with all suffixes equal to `ch` repeatedly removed.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("**text**");
+    ///
+    /// let subslice: &mut str = text.trim_end_matches_char_mut('*');
+    /// assert_eq!(subslice, "**text");
+    /// ```
+    fn trim_end_matches_char_mut(&mut self, ch: char) -> &mut str;
+
+    /// Returns a mutable string slice with all prefixes and suffixes equal to `pat` repeatedly removed.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("abab text abab");
+    ///
+    /// let subslice: &mut str = text.trim_matches_str_mut("ab");
+    /// assert_eq!(subslice, " text ");
+    /// ```
+    fn trim_matches_str_mut(&mut self, pat: &str) -> &mut str;
+
+    /// Returns a mutable string slice with a single trailing line terminator removed:
+    /// `\r\n`, `\n` or `\r`. Other whitespace is kept.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from(" line \r\n");
+    ///
+    /// let subslice: &mut str = text.trim_end_newline_mut();
+    /// assert_eq!(subslice, " line ");
+    /// ```
+    fn trim_end_newline_mut(&mut self) -> &mut str;
+
+    /// Centers content stored in the first `content_len` bytes of this string slice,
+    /// filling the rest of it with spaces. Left margin is never longer than the right one.
+    ///
+    /// Bytes after `content_len` are treated as scratch space and overwritten.
+    ///
+    /// **Panics** if `content_len` is greater than [`str::len`] or is not on a char boundary.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hi____");
+    ///
+    /// text.center_in_place(2);
+    /// assert_eq!(text, "  Hi  ");
+    /// ```
+    fn center_in_place(&mut self, content_len: usize);
+
+    /// Returns iterator over string slices of `n` consecutive characters, sliding by one
+    /// character at a time.
+    ///
+    /// Yields nothing if this string slice contains less than `n` characters.
+    ///
+    /// **Panics** if `n` is zero.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut windows = "abcd".windows_chars(2);
+    ///
+    /// assert_eq!(windows.next(), Some("ab"));
+    /// assert_eq!(windows.next(), Some("bc"));
+    /// assert_eq!(windows.next(), Some("cd"));
+    /// assert_eq!(windows.next(), None);
+    /// ```
+    fn windows_chars(&self, n: usize) -> WindowsChars<'_>;
+
+    /// Returns iterator over words of this string slice separated by any amount of ASCII
+    /// whitespace. Works byte-wise just like [`str::split_ascii_whitespace`].
+    ///
+    /// Unicode whitespace such as `U+00A0` is not treated as a separator.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut words = " Hello \t World\u{A0}!\n".split_ascii_whitespace_in_place();
+    ///
+    /// assert_eq!(words.next(), Some("Hello"));
+    /// assert_eq!(words.next(), Some("World\u{A0}!"));
+    /// assert_eq!(words.next(), None);
+    /// ```
+    fn split_ascii_whitespace_in_place(&self) -> SplitAsciiWhitespace<'_>;
+
+    /// Returns number of occurrences of byte `b` in this string slice.
+    ///
+    /// Only meaningful for ASCII bytes. Multi-byte characters are encoded using bytes
+    /// greater than `0x7F`, so non-ASCII `b` would match parts of other characters.
+    ///
+    /// See [`StrExt::count_char`] to count characters.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// assert_eq!("Hello".count_byte(b'l'), 2);
+    /// ```
+    fn count_byte(&self, b: u8) -> usize;
+
+    /// Alias of [`StrExt::count_byte`].
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// assert_eq!("one\ntwo\nthree".byte_count(b'\n'), 2);
+    /// ```
+    fn byte_count(&self, b: u8) -> usize {
+        self.count_byte(b)
+    }
+
+    /// Returns number of occurrences of character `ch` in this string slice.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// assert_eq!("Hello".count_char('l'), 2);
+    /// assert_eq!("日本語の本".count_char('本'), 2);
+    /// ```
+    fn count_char(&self, ch: char) -> usize;
+
+    /// Returns iterator over lines of this string slice together with byte indices of
+    /// their starts.
+    ///
+    /// Lines are split just like in [`str::lines`]: at `\n` or `\r\n`, line terminators
+    /// are not included and final empty line is skipped.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut lines = "a\nbb\r\nccc\n".line_indices();
+    ///
+    /// assert_eq!(lines.next(), Some((0, "a")));
+    /// assert_eq!(lines.next(), Some((2, "bb")));
+    /// assert_eq!(lines.next(), Some((6, "ccc")));
+    /// assert_eq!(lines.next(), None);
+    /// ```
+    fn line_indices(&self) -> LineIndices<'_>;
+
+    /// Fills `buf` with repeated copies of this string slice and returns filled prefix of it.
+    ///
+    /// Final copy is truncated at a char boundary, so multi-byte characters are never
+    /// split. Bytes of `buf` past the returned prefix are overwritten with spaces.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut buf = String::from("_____");
+    ///
+    /// assert_eq!("ab".repeat_into(&mut buf), "ababa");
+    ///
+    /// assert_eq!("é".repeat_into(&mut buf), "éé");
+    /// assert_eq!(buf, "éé ");
+    /// ```
+    fn repeat_into<'b>(&self, buf: &'b mut str) -> &'b str;
+
+    /// Returns iterator over byte indices of all char boundaries of this string slice,
+    /// including `0` and [`str::len`].
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut boundaries = "aé".char_boundaries();
+    ///
+    /// assert_eq!(boundaries.next(), Some(0));
+    /// assert_eq!(boundaries.next(), Some(1));
+    /// assert_eq!(boundaries.next(), Some(3));
+    /// assert_eq!(boundaries.next(), None);
+    /// ```
+    fn char_boundaries(&self) -> CharBoundaries<'_>;
+
+    /// Returns iterator over alternating runs of characters which do not match and which
+    /// do match `pred`.
+    ///
+    /// Concatenation of all yielded string slices is equal to original string slice.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut runs = "a, b".split_keep_delim(|ch| ch == ',' || ch == ' ');
+    ///
+    /// assert_eq!(runs.next(), Some("a"));
+    /// assert_eq!(runs.next(), Some(", "));
+    /// assert_eq!(runs.next(), Some("b"));
+    /// assert_eq!(runs.next(), None);
+    /// ```
+    fn split_keep_delim<P: FnMut(char) -> bool>(&self, pred: P) -> SplitKeepDelim<'_, P>;
+
+    /// Returns [`StringView`] from opening delimiter at the start of this string slice to
+    /// the matching closing delimiter, respecting nesting.
+    ///
+    /// Returns `None` if string slice doesn't start with `open` or delimiters are unbalanced.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "(a(b)c)d";
+    ///
+    /// let view = text.match_delimiter('(', ')').unwrap();
+    /// assert_eq!(view.as_str(), "(a(b)c)");
+    ///
+    /// assert!("(a(b)".match_delimiter('(', ')').is_none());
+    /// ```
+    fn match_delimiter(&self, open: char, close: char) -> Option<StringView<'_>>;
+
+    /// Calls `func` with mutable string slice of every line of this string slice.
+    ///
+    /// Lines are split just like in [`str::lines`]: at `\n` or `\r\n`, line terminators
+    /// are not included and final empty line is skipped.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("  hello\r\n world  \n");
+    ///
+    /// text.for_each_line_mut(|line| line.trim_mut().make_uppercase());
+    /// assert_eq!(text, "  HELLO\r\n WORLD  \n");
+    /// ```
+    fn for_each_line_mut<F: FnMut(&mut str)>(&mut self, func: F);
+
+    /// Replaces every `\r\n` with `\n` by moving bytes to the left in-place. Returns shortened
+    /// string slice. Lone `\r` characters are left as is.
+    ///
+    /// Bytes past the returned string slice are overwritten with spaces.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("a\r\nb\r\nc");
+    ///
+    /// let normalized = text.normalize_newlines_in_place();
+    /// assert_eq!(normalized, "a\nb\nc");
+    /// assert_eq!(normalized.len(), 5);
+    ///
+    /// assert_eq!(text, "a\nb\nc  ");
+    /// ```
+    fn normalize_newlines_in_place(&mut self) -> &mut str;
+
+    /// Removes the longest common leading whitespace of all non-blank lines from every line
+    /// by moving bytes to the left in-place. Returns shortened string slice.
+    ///
+    /// Whitespace-only lines are emptied, their line terminators are kept. Bytes past the
+    /// returned string slice are overwritten with spaces.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("    fn main() {\n        body();\n\n    }\n");
+    ///
+    /// let dedented = text.dedent_in_place();
+    /// assert_eq!(dedented, "fn main() {\n    body();\n\n}\n");
+    /// ```
+    fn dedent_in_place(&mut self) -> &mut str;
+
+    /// Returns `n`-th character of this string slice together with its starting byte index.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "aé本";
+    ///
+    /// let (ch, idx) = text.char_at_char_idx(2).unwrap();
+    /// assert_eq!(ch.as_str(), "本");
+    /// assert_eq!(idx, 3);
+    ///
+    /// assert!(text.char_at_char_idx(3).is_none());
+    /// ```
+    fn char_at_char_idx(&self, n: usize) -> Option<(Char<'_>, usize)>;
+
+    /// Splits this string slice at the first character boundary at or after `byte`.
+    /// Splits at the end of string slice if `byte` is past it.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "aé本";
+    ///
+    /// assert_eq!(text.split_at_boundary_after(1), ("a", "é本"));
+    /// assert_eq!(text.split_at_boundary_after(2), ("aé", "本"));
+    /// assert_eq!(text.split_at_boundary_after(4), ("aé本", ""));
+    /// ```
+    fn split_at_boundary_after(&self, byte: usize) -> (&str, &str);
+
+    /// Checks if characters of this string slice are sorted in non-decreasing order.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// assert!("abcz".is_sorted_chars());
+    /// assert!(!"acb".is_sorted_chars());
+    /// ```
+    fn is_sorted_chars(&self) -> bool;
+
+    /// Checks if characters of this string slice are sorted using `compare`, which
+    /// has to return `true` if two consecutive characters are in order.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// assert!("zyxa".is_sorted_chars_by(|a, b| a >= b));
+    /// assert!(!"aBc".is_sorted_chars_by(|a, b| a <= b));
+    /// assert!("aBc".is_sorted_chars_by(|a, b| a.to_ascii_lowercase() <= b.to_ascii_lowercase()));
+    /// ```
+    fn is_sorted_chars_by<F: FnMut(char, char) -> bool>(&self, compare: F) -> bool;
+
+    /// Approximate number of terminal columns this string slice takes.
+    ///
+    /// With `width` feature enabled every character is measured using `unicode-width`:
+    /// wide characters such as CJK take 2 columns, control characters take none.
+    /// Without it every character is assumed to take a single column.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// assert_eq!("Hello".display_width(), 5);
+    ///
+    /// #[cfg(feature = "width")]
+    /// assert_eq!("日本".display_width(), 4);
+    /// ```
+    fn display_width(&self) -> usize;
+
+    /// Removes ANSI CSI escape sequences (`ESC [ ... final byte`, e.g. `\x1b[31m`) by moving
+    /// bytes to the left in-place. Returns shortened string slice.
+    ///
+    /// Unterminated sequences are left as is. Bytes past the returned string slice are
+    /// overwritten with spaces.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("\x1b[1;31mred\x1b[0m");
+    ///
+    /// assert_eq!(text.strip_ansi_in_place(), "red");
+    /// ```
+    fn strip_ansi_in_place(&mut self) -> &mut str;
+
+    /// Increments `counts[b]` for every byte `b` of this string slice.
+    ///
+    /// Raw `UTF-8` bytes are counted, so only entries below `0x80` correspond to characters.
+    /// Entries starting from `0x80` count lead and continuation bytes of multibyte characters.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut counts = [0; 256];
+    /// "aabbb".ascii_byte_counts(&mut counts);
+    ///
+    /// assert_eq!(counts[b'a' as usize], 2);
+    /// assert_eq!(counts[b'b' as usize], 3);
+    /// ```
+    fn ascii_byte_counts(&self, counts: &mut [usize; 256]);
+
+    /// Returns `n`-th line of this string slice without line terminator.
+    /// Lines are split the same way as in [`str::lines`].
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "one\ntwo\r\nthree\nfour";
+    ///
+    /// assert_eq!(text.nth_line(2), Some("three"));
+    /// assert_eq!(text.nth_line(4), None);
+    /// ```
+    fn nth_line(&self, n: usize) -> Option<&str>;
+
+    /// Returns iterator over views of maximal runs of ASCII digits of this string slice.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "took 125ms, retried 3 times";
+    ///
+    /// let numbers: Vec<u32> = text
+    ///     .ascii_number_spans()
+    ///     .map(|number| number.as_str().parse().unwrap())
+    ///     .collect();
+    ///
+    /// assert_eq!(numbers, [125, 3]);
+    /// ```
+    fn ascii_number_spans(&self) -> NumberSpans<'_>;
+
+    /// Collapses trailing line terminators (`\n` or `\r\n`) into the first one of them.
+    /// Returns shortened string slice. Bytes past it are overwritten with spaces.
+    ///
+    /// Length of string slice can't grow, so terminator is never added if there is none.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("text\n\n\n");
+    /// assert_eq!(text.collapse_trailing_newlines_in_place(), "text\n");
+    ///
+    /// let mut text = String::from("text");
+    /// assert_eq!(text.collapse_trailing_newlines_in_place(), "text");
+    /// ```
+    fn collapse_trailing_newlines_in_place(&mut self) -> &mut str;
+
+    /// Splits this string slice after `n` characters. Returns [`None`] if string slice
+    /// has less than `n` characters.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// assert_eq!("abc".split_at_char_checked(2), Some(("ab", "c")));
+    /// assert_eq!("abc".split_at_char_checked(5), None);
+    /// ```
+    fn split_at_char_checked(&self, n: usize) -> Option<(&str, &str)>;
+
+    /// Returns iterator over non-overlapping mutable string slices of up to `n` characters each.
+    ///
+    /// **Panics** if `n` is zero.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("abcdefg");
+    ///
+    /// text.char_chunks_mut(3)
+    ///     .step_by(2)
+    ///     .for_each(|chunk| chunk.make_ascii_uppercase());
+    ///
+    /// assert_eq!(text, "ABCdefG");
+    /// ```
+    fn char_chunks_mut(&mut self, n: usize) -> CharChunksMut<'_>;
+
+    /// Returns `n`-th whitespace-separated word of this string slice.
+    /// Words are split the same way as in [`str::split_whitespace`].
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "the quick brown fox";
+    ///
+    /// assert_eq!(text.nth_word(2), Some("brown"));
+    /// assert_eq!(text.nth_word(4), None);
+    /// ```
+    fn nth_word(&self, n: usize) -> Option<&str>;
+
+    /// Stably moves all characters matching `pred` to the front of this string slice in-place.
+    /// Relative order of characters inside both groups is preserved.
+    /// Returns byte index of the boundary between the two groups.
+    ///
+    /// returns [`Err`] and leaves string slice unmodified if its characters have different sizes,
+    /// since they cannot be moved in-place otherwise.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("a1b2c3");
+    ///
+    /// let boundary = text.partition_in_place(char::is_alphabetic).unwrap();
+    /// assert_eq!(text, "abc123");
+    /// assert_eq!(boundary, 3);
+    ///
+    /// let mut text = String::from("aж");
+    /// assert!(text.partition_in_place(char::is_alphabetic).is_err());
+    /// ```
+    fn partition_in_place<P: FnMut(char) -> bool>(
+        &mut self,
+        pred: P,
+    ) -> Result<usize, CharsHaveDifferentSizes>;
+
+    /// Number of non-overlapping occurrences of `needle` in this string slice.
+    ///
+    /// Same as `self.matches(needle).count()`. Empty `needle` matches at every char boundary.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// assert_eq!("ababab".count_occurrences("ab"), 3);
+    /// assert_eq!("aaa".count_occurrences("aa"), 1);
+    /// assert_eq!("Привет".count_occurrences(""), 7);
+    /// ```
+    fn count_occurrences(&self, needle: &str) -> usize;
+
+    /// Returns iterator over at most first `n` lines of this string slice.
+    /// Lines are split the same way as in [`str::lines`].
+    ///
+    /// Stops after `n`-th line without scanning the rest of the string slice.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "one\ntwo\r\nthree\nfour";
+    /// let mut lines = text.lines_take(2);
+    ///
+    /// assert_eq!(lines.next(), Some("one"));
+    /// assert_eq!(lines.next(), Some("two"));
+    /// assert_eq!(lines.next(), None);
+    /// ```
+    fn lines_take(&self, n: usize) -> Take<Lines<'_>>;
+
+    /// Returns byte index of the first non-whitespace character of this string slice,
+    /// or [`None`] if it is empty or consists of whitespace only.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// assert_eq!("  hi  ".first_non_whitespace_idx(), Some(2));
+    /// assert_eq!(" \t\n".first_non_whitespace_idx(), None);
+    /// ```
+    fn first_non_whitespace_idx(&self) -> Option<usize>;
+
+    /// Returns byte index of the start of the last non-whitespace character of this string slice,
+    /// or [`None`] if it is empty or consists of whitespace only.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// assert_eq!("  hi  ".last_non_whitespace_idx(), Some(3));
+    /// assert_eq!(" мир ".last_non_whitespace_idx(), Some(5));
+    /// assert_eq!("".last_non_whitespace_idx(), None);
+    /// ```
+    fn last_non_whitespace_idx(&self) -> Option<usize>;
 }
 
 impl StrExt for str {
@@ -223,6 +828,16 @@ impl StrExt for str {
         StringViewMut::new_part(self, start_idx, end_idx)
     }
 
+    fn view_range<R: RangeBounds<usize>>(&self, range: R) -> StringView<'_> {
+        let (start_idx, end_idx) = resolve_range(self, range);
+        StringView::new_part(self, start_idx, end_idx)
+    }
+
+    fn view_range_mut<R: RangeBounds<usize>>(&mut self, range: R) -> StringViewMut<'_> {
+        let (start_idx, end_idx) = resolve_range(self, range);
+        StringViewMut::new_part(self, start_idx, end_idx)
+    }
+
     fn chars_in_place(&self) -> CharsInPlace<'_> {
         CharsInPlace::new(self)
     }
@@ -263,15 +878,22 @@ impl StrExt for str {
     }
 
     fn replace_in_place(&mut self, rep: &str) {
-        assert_eq!(
-            self.len(),
-            rep.len(),
-            "replacement string slice has to have the same size as the original. Consider creating mutable subslice with different length"
-        );
+        self.try_replace_in_place(rep)
+            .expect("Unable to replace string slice in-place")
+    }
+
+    fn try_replace_in_place(&mut self, rep: &str) -> Result<(), ReplaceError> {
+        if self.len() != rep.len() {
+            return Err(ReplaceError {
+                expected: self.len(),
+                found: rep.len(),
+            });
+        }
         // SAFETY: rep is str so self is valid after copy_from_slice
         unsafe {
             self.as_bytes_mut().copy_from_slice(rep.as_bytes());
         }
+        Ok(())
     }
 
     fn replace_with_char(&mut self, ch: char) {
@@ -279,7 +901,7 @@ impl StrExt for str {
         let replacement_char_len = ch.len_utf8();
 
         assert!(
-            len % replacement_char_len == 0,
+            len.is_multiple_of(replacement_char_len),
             "This string slice cannot be fully replaced by this character. Consider creating mutable subslice with different length"
         );
 
@@ -288,7 +910,8 @@ impl StrExt for str {
             let bytes = self.as_bytes_mut();
 
             for idx in 0..(len / replacement_char_len) {
-                ch.encode_utf8(&mut bytes[idx..idx + replacement_char_len]);
+                let start = idx * replacement_char_len;
+                ch.encode_utf8(&mut bytes[start..start + replacement_char_len]);
             }
         }
     }
@@ -310,4 +933,454 @@ impl StrExt for str {
     fn trim_mut(&mut self) -> &mut str {
         self.trim_matches_mut(char::is_whitespace)
     }
+
+    fn trim_matches_char_mut(&mut self, ch: char) -> &mut str {
+        self.trim_matches_mut(|c| c == ch)
+    }
+
+    fn trim_start_matches_char_mut(&mut self, ch: char) -> &mut str {
+        let start_idx = self.len() - self.trim_start_matches(ch).len();
+
+        // SAFETY: start index is returned by `str::trim_start_matches`
+        unsafe { self.get_unchecked_mut(start_idx..) }
+    }
+
+    fn trim_end_matches_char_mut(&mut self, ch: char) -> &mut str {
+        let end_idx = self.trim_end_matches(ch).len();
+
+        // SAFETY: end index is returned by `str::trim_end_matches`
+        unsafe { self.get_unchecked_mut(..end_idx) }
+    }
+
+    fn trim_matches_str_mut(&mut self, pat: &str) -> &mut str {
+        // `&str` searcher is not double-ended, so `str::trim_matches` can't be used.
+        let trimmed_start = self.trim_start_matches(pat);
+        let start_idx = self.len() - trimmed_start.len();
+        let end_idx = start_idx + trimmed_start.trim_end_matches(pat).len();
+
+        // SAFETY: start & end indices returned by `str::trim_start_matches` & `str::trim_end_matches`
+        unsafe { self.get_unchecked_mut(start_idx..end_idx) }
+    }
+
+    fn trim_end_newline_mut(&mut self) -> &mut str {
+        let trimmed = self
+            .strip_suffix("\r\n")
+            .or_else(|| self.strip_suffix('\n'))
+            .or_else(|| self.strip_suffix('\r'))
+            .unwrap_or(self);
+        let end_idx = trimmed.len();
+
+        // SAFETY: end index is returned by `str::strip_suffix`
+        unsafe { self.get_unchecked_mut(..end_idx) }
+    }
+
+    fn center_in_place(&mut self, content_len: usize) {
+        assert!(
+            self.is_char_boundary(content_len),
+            "Content length has to be inside this string slice and on a char boundary"
+        );
+        let len = self.len();
+        let left_margin = (len - content_len) / 2;
+
+        // SAFETY: content is moved as a whole and ends on a char boundary,
+        // everything around it is overwritten with ASCII spaces.
+        unsafe {
+            let bytes = self.as_bytes_mut();
+
+            bytes.copy_within(..content_len, left_margin);
+            bytes[..left_margin].fill(b' ');
+            bytes[left_margin + content_len..].fill(b' ');
+        }
+    }
+
+    fn windows_chars(&self, n: usize) -> WindowsChars<'_> {
+        WindowsChars::new(self, n)
+    }
+
+    fn split_ascii_whitespace_in_place(&self) -> SplitAsciiWhitespace<'_> {
+        SplitAsciiWhitespace::new(self)
+    }
+
+    fn count_byte(&self, b: u8) -> usize {
+        self.as_bytes().iter().filter(|&&byte| byte == b).count()
+    }
+
+    fn count_char(&self, ch: char) -> usize {
+        self.chars().filter(|&c| c == ch).count()
+    }
+
+    fn line_indices(&self) -> LineIndices<'_> {
+        LineIndices::new(self)
+    }
+
+    fn repeat_into<'b>(&self, buf: &'b mut str) -> &'b str {
+        let mut filled = 0;
+
+        // SAFETY: only whole characters of self and ASCII spaces are written,
+        // every byte of buf is overwritten.
+        unsafe {
+            let bytes = buf.as_bytes_mut();
+
+            if !self.is_empty() {
+                while filled + self.len() <= bytes.len() {
+                    bytes[filled..filled + self.len()].copy_from_slice(self.as_bytes());
+                    filled += self.len();
+                }
+                let mut partial_len = bytes.len() - filled;
+                while !self.is_char_boundary(partial_len) {
+                    partial_len -= 1;
+                }
+                bytes[filled..filled + partial_len]
+                    .copy_from_slice(&self.as_bytes()[..partial_len]);
+                filled += partial_len;
+            }
+            bytes[filled..].fill(b' ');
+        }
+        &buf[..filled]
+    }
+
+    fn char_boundaries(&self) -> CharBoundaries<'_> {
+        CharBoundaries::new(self)
+    }
+
+    fn split_keep_delim<P: FnMut(char) -> bool>(&self, pred: P) -> SplitKeepDelim<'_, P> {
+        SplitKeepDelim::new(self, pred)
+    }
+
+    fn match_delimiter(&self, open: char, close: char) -> Option<StringView<'_>> {
+        if !self.starts_with(open) {
+            return None;
+        }
+        let mut depth = 0_usize;
+
+        for (idx, ch) in self.view().char_indices_in_place() {
+            if ch == close && depth > 0 {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(self.view_part(0, idx + ch.as_str().len()));
+                }
+            }
+            else if ch == open {
+                depth += 1;
+            }
+        }
+        None
+    }
+
+    fn for_each_line_mut<F: FnMut(&mut str)>(&mut self, mut func: F) {
+        let mut rest: &mut str = self;
+
+        while !rest.is_empty() {
+            let rest_len = rest.len();
+            let line_len = rest
+                .as_bytes()
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(rest_len, |idx| idx + 1);
+
+            let (line, next) = core::mem::take(&mut rest).split_at_mut(line_len);
+
+            let terminator_len = if line.ends_with("\r\n") {
+                2
+            }
+            else if line.ends_with('\n') {
+                1
+            }
+            else {
+                0
+            };
+            func(&mut line[..line_len - terminator_len]);
+
+            rest = next;
+        }
+    }
+
+    fn normalize_newlines_in_place(&mut self) -> &mut str {
+        let mut new_len = 0;
+
+        // SAFETY: only ASCII `\r` bytes are removed
+        unsafe {
+            let bytes = self.as_bytes_mut();
+
+            for idx in 0..bytes.len() {
+                if bytes[idx] == b'\r' && bytes.get(idx + 1) == Some(&b'\n') {
+                    continue;
+                }
+                bytes[new_len] = bytes[idx];
+                new_len += 1;
+            }
+            truncate_in_place(self, new_len)
+        }
+    }
+
+    fn dedent_in_place(&mut self) -> &mut str {
+        let mut common_indent: Option<&str> = None;
+
+        for line in self.lines().filter(|line| !line.trim().is_empty()) {
+            let indent = &line[..line.len() - line.trim_start().len()];
+
+            common_indent = Some(match common_indent {
+                None => indent,
+                Some(common) => {
+                    let common_len = common
+                        .chars()
+                        .zip(indent.chars())
+                        .take_while(|(a, b)| a == b)
+                        .map(|(ch, _)| ch.len_utf8())
+                        .sum();
+                    &common[..common_len]
+                }
+            });
+        }
+        let indent_len = common_indent.map_or(0, str::len);
+
+        let len = self.len();
+        let mut read = 0;
+        let mut write = 0;
+
+        // SAFETY: every non-blank line starts with common indent, so only whole whitespace
+        // characters are removed from line starts.
+        unsafe {
+            while read < len {
+                let line_end = self.as_bytes()[read..]
+                    .iter()
+                    .position(|&b| b == b'\n')
+                    .map_or(len, |idx| read + idx + 1);
+
+                let line = &self[read..line_end];
+                let content = line.strip_suffix('\n').unwrap_or(line);
+                let content = content.strip_suffix('\r').unwrap_or(content);
+
+                let removed_len = if content.trim().is_empty() {
+                    content.len()
+                }
+                else {
+                    indent_len
+                };
+                self.as_bytes_mut()
+                    .copy_within(read + removed_len..line_end, write);
+
+                write += line_end - read - removed_len;
+                read = line_end;
+            }
+            truncate_in_place(self, write)
+        }
+    }
+
+    fn char_at_char_idx(&self, n: usize) -> Option<(Char<'_>, usize)> {
+        CharIndicesInPlace::new(self, 0)
+            .nth(n)
+            .map(|(idx, ch)| (ch, idx))
+    }
+
+    fn split_at_boundary_after(&self, byte: usize) -> (&str, &str) {
+        let idx = (byte..self.len())
+            .find(|&idx| self.is_char_boundary(idx))
+            .unwrap_or(self.len());
+
+        self.split_at(idx)
+    }
+
+    fn is_sorted_chars(&self) -> bool {
+        self.is_sorted_chars_by(|a, b| a <= b)
+    }
+
+    fn is_sorted_chars_by<F: FnMut(char, char) -> bool>(&self, mut compare: F) -> bool {
+        let mut chars = self.chars();
+        let Some(mut prev) = chars.next()
+        else {
+            return true;
+        };
+
+        chars.all(|ch| {
+            let in_order = compare(prev, ch);
+            prev = ch;
+            in_order
+        })
+    }
+
+    fn display_width(&self) -> usize {
+        self.chars().map(char_display_width).sum()
+    }
+
+    fn strip_ansi_in_place(&mut self) -> &mut str {
+        let mut new_len = 0;
+
+        // SAFETY: only ASCII bytes of escape sequences are removed
+        unsafe {
+            let bytes = self.as_bytes_mut();
+            let mut idx = 0;
+
+            while idx < bytes.len() {
+                if bytes[idx] == 0x1b && bytes.get(idx + 1) == Some(&b'[') {
+                    // Parameter and intermediate bytes followed by a final byte
+                    let sequence_len = bytes[idx + 2..]
+                        .iter()
+                        .position(|b| !(0x20..=0x3f).contains(b))
+                        .map(|params_len| idx + 2 + params_len)
+                        .filter(|&final_idx| (0x40..=0x7e).contains(&bytes[final_idx]))
+                        .map(|final_idx| final_idx + 1 - idx);
+
+                    if let Some(sequence_len) = sequence_len {
+                        idx += sequence_len;
+                        continue;
+                    }
+                }
+                bytes[new_len] = bytes[idx];
+                new_len += 1;
+                idx += 1;
+            }
+            truncate_in_place(self, new_len)
+        }
+    }
+
+    fn ascii_byte_counts(&self, counts: &mut [usize; 256]) {
+        for &b in self.as_bytes() {
+            counts[b as usize] += 1;
+        }
+    }
+
+    fn nth_line(&self, n: usize) -> Option<&str> {
+        self.lines().nth(n)
+    }
+
+    fn ascii_number_spans(&self) -> NumberSpans<'_> {
+        NumberSpans::new(self)
+    }
+
+    fn collapse_trailing_newlines_in_place(&mut self) -> &mut str {
+        let mut content = &*self;
+        while let Some(rest) = content.strip_suffix('\n') {
+            content = rest.strip_suffix('\r').unwrap_or(rest);
+        }
+        let content_len = content.len();
+
+        let new_len = match &self.as_bytes()[content_len..] {
+            [] => content_len,
+            [b'\r', ..] => content_len + 2,
+            _ => content_len + 1,
+        };
+
+        // SAFETY: new length is on a boundary of ASCII line terminator
+        unsafe { truncate_in_place(self, new_len) }
+    }
+
+    fn split_at_char_checked(&self, n: usize) -> Option<(&str, &str)> {
+        let idx = self
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .chain(core::iter::once(self.len()))
+            .nth(n)?;
+
+        Some(self.split_at(idx))
+    }
+
+    fn char_chunks_mut(&mut self, n: usize) -> CharChunksMut<'_> {
+        CharChunksMut::new(self, n)
+    }
+
+    fn nth_word(&self, n: usize) -> Option<&str> {
+        self.split_whitespace().nth(n)
+    }
+
+    fn partition_in_place<P: FnMut(char) -> bool>(
+        &mut self,
+        mut pred: P,
+    ) -> Result<usize, CharsHaveDifferentSizes> {
+        let Some(char_len) = self.chars().next().map(char::len_utf8)
+        else {
+            return Ok(0);
+        };
+        if let Some(found) = self
+            .chars()
+            .map(char::len_utf8)
+            .find(|&len| len != char_len)
+        {
+            return Err(CharsHaveDifferentSizes {
+                expected: char_len,
+                found,
+            });
+        }
+
+        let mut boundary = 0;
+        for idx in (0..self.len()).step_by(char_len) {
+            let ch = self[idx..].chars().next().unwrap_or_default();
+            if pred(ch) {
+                // SAFETY: every character takes `char_len` bytes, so rotating by `char_len`
+                // moves whole characters and keeps string slice valid
+                unsafe {
+                    self.as_bytes_mut()[boundary..idx + char_len].rotate_right(char_len);
+                }
+                boundary += char_len;
+            }
+        }
+        Ok(boundary)
+    }
+
+    fn count_occurrences(&self, needle: &str) -> usize {
+        self.matches(needle).count()
+    }
+
+    fn lines_take(&self, n: usize) -> Take<Lines<'_>> {
+        self.lines().take(n)
+    }
+
+    fn first_non_whitespace_idx(&self) -> Option<usize> {
+        self.find(|ch: char| !ch.is_whitespace())
+    }
+
+    fn last_non_whitespace_idx(&self) -> Option<usize> {
+        self.rfind(|ch: char| !ch.is_whitespace())
+    }
+}
+
+/// Overwrites bytes of `s` past `len` with spaces and returns string slice of the first
+/// `len` bytes.
+///
+/// # Safety
+///
+/// The caller must ensure that `s[..len]` is valid `UTF-8`.
+unsafe fn truncate_in_place(s: &mut str, len: usize) -> &mut str {
+    // SAFETY: caller guarantees `s[..len]` is valid, the rest is overwritten with ASCII.
+    unsafe {
+        let bytes = s.as_bytes_mut();
+        bytes[len..].fill(b' ');
+
+        str::from_utf8_unchecked_mut(&mut bytes[..len])
+    }
+}
+
+/// Number of terminal columns character takes. See [`StrExt::display_width`].
+fn char_display_width(ch: char) -> usize {
+    #[cfg(feature = "width")]
+    {
+        ch.width().unwrap_or(0)
+    }
+    #[cfg(not(feature = "width"))]
+    {
+        let _ = ch;
+        1
+    }
+}
+
+/// Resolves `range` against string slice `s` into start and end byte indices.
+///
+/// **Panics** if range is out of bounds or does not lie on character boundaries.
+fn resolve_range<R: RangeBounds<usize>>(s: &str, range: R) -> (usize, usize) {
+    let start_idx = match range.start_bound() {
+        Bound::Included(&idx) => idx,
+        Bound::Excluded(&idx) => idx.checked_add(1).expect("Range start index overflow"),
+        Bound::Unbounded => 0,
+    };
+    let end_idx = match range.end_bound() {
+        Bound::Included(&idx) => idx.checked_add(1).expect("Range end index overflow"),
+        Bound::Excluded(&idx) => idx,
+        Bound::Unbounded => s.len(),
+    };
+    assert!(
+        start_idx <= end_idx && s.is_char_boundary(start_idx) && s.is_char_boundary(end_idx),
+        "Range {start_idx}..{end_idx} is out of bounds or does not lie on character boundaries"
+    );
+    (start_idx, end_idx)
 }
@@ -1,5 +1,16 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::borrow::Borrow;
+use core::cmp::Ordering;
 use core::error::Error;
 use core::fmt::{Debug, Display};
+use core::hash::{Hash, Hasher};
+use core::iter::Rev;
+
+use crate::{
+    Char, CharIndicesInPlace, CharMut, CharsHaveDifferentSizes, CharsInPlace, CharsInPlaceMut,
+    ReplaceError, SplitLines, SplitNChars, StrExt,
+};
 
 /// Immutable view into string slice.
 ///
@@ -82,6 +93,55 @@ impl<'a> StringView<'a> {
         &self.0.base[self.0.view_start..self.0.view_start + self.0.view_len]
     }
 
+    /// Returns string slice of the first `n` characters of this view.
+    ///
+    /// Whole view is returned if it contains less than `n` characters.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Привет Мир";
+    /// let view = text.view_part(13, 19);
+    /// assert_eq!(view.as_str(), "Мир");
+    ///
+    /// assert_eq!(view.str_prefix(2), "Ми");
+    /// assert_eq!(view.str_prefix(10), "Мир");
+    /// ```
+    pub fn str_prefix(&self, n: usize) -> &'a str {
+        let text = self.as_str();
+        let end = text
+            .char_indices()
+            .nth(n)
+            .map_or(text.len(), |(idx, _)| idx);
+        &text[..end]
+    }
+
+    /// Returns string slice of the last `n` characters of this view.
+    ///
+    /// Whole view is returned if it contains less than `n` characters.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Привет Мир";
+    /// let view = text.view_part(13, 19);
+    /// assert_eq!(view.as_str(), "Мир");
+    ///
+    /// assert_eq!(view.str_suffix(2), "ир");
+    /// assert_eq!(view.str_suffix(10), "Мир");
+    /// ```
+    pub fn str_suffix(&self, n: usize) -> &'a str {
+        let text = self.as_str();
+        let start = match n {
+            0 => text.len(),
+            _ => text
+                .char_indices()
+                .nth_back(n - 1)
+                .map_or(0, |(idx, _)| idx),
+        };
+        &text[start..]
+    }
+
     /// Shrinks this view from the left to current right edge with length zero.
     ///
     /// ```toml,ignore
@@ -165,7 +225,7 @@ impl<'a> StringView<'a> {
     /// returns [`Err`] if there is not enough characters in base string to the right of this view.
     ///
     /// ```rust
-    /// use string_view::StrExt;
+    /// use string_view::{Side, StrExt};
     ///
     /// let text = "Hello World";
     ///
@@ -177,10 +237,10 @@ impl<'a> StringView<'a> {
     /// assert_eq!(view.as_str(), "Hello Wor");
     ///
     /// let err = view.try_extend_right(10);
-    /// assert!(matches!(err, Err(BaseStringIsTooShort)));
+    /// assert_eq!(err.unwrap_err().side(), Side::Right);
     /// assert_eq!(view.as_str(), "Hello Wor");
     /// ```
-    pub fn try_extend_right(&mut self, n: usize) -> Result<(), BaseStringIsTooShort<RIGHT>> {
+    pub fn try_extend_right(&mut self, n: usize) -> Result<(), BaseStringIsTooShort> {
         self.0.try_extend_right(n)
     }
 
@@ -249,7 +309,7 @@ impl<'a> StringView<'a> {
     /// returns [`Err`] if there is not enough characters in current string view.
     ///
     /// ```rust
-    /// use string_view::StrExt;
+    /// use string_view::{Side, StrExt};
     ///
     /// let text = "One and only Hello World";
     ///
@@ -261,10 +321,10 @@ impl<'a> StringView<'a> {
     /// assert_eq!(view.as_str(), "Hello");
     ///
     /// let result = view.try_reduce_right(10);
-    /// assert!(matches!(result, Err(ViewIsTooShort)));
+    /// assert_eq!(result.unwrap_err().side(), Side::Right);
     /// assert_eq!(view.as_str(), "Hello");
     /// ```
-    pub fn try_reduce_right(&mut self, n: usize) -> Result<(), ViewIsTooShort<RIGHT>> {
+    pub fn try_reduce_right(&mut self, n: usize) -> Result<(), ViewIsTooShort> {
         self.0.try_reduce_right(n)
     }
 
@@ -332,7 +392,7 @@ impl<'a> StringView<'a> {
     /// returns [`Err`] if there is not enough characters in base string to the right of this view.
     ///
     /// ```rust
-    /// use string_view::StrExt;
+    /// use string_view::{Side, StrExt};
     ///
     /// let text = "Hello World";
     ///
@@ -344,10 +404,10 @@ impl<'a> StringView<'a> {
     /// assert_eq!(view.as_str(), "llo World");
     ///
     /// let err = view.try_extend_left(10);
-    /// assert!(matches!(err, Err(BaseStringIsTooShort)));
+    /// assert_eq!(err.unwrap_err().side(), Side::Left);
     /// assert_eq!(view.as_str(), "llo World");
     /// ```
-    pub fn try_extend_left(&mut self, n: usize) -> Result<(), BaseStringIsTooShort<LEFT>> {
+    pub fn try_extend_left(&mut self, n: usize) -> Result<(), BaseStringIsTooShort> {
         self.0.try_extend_left(n)
     }
 
@@ -418,7 +478,7 @@ impl<'a> StringView<'a> {
     /// returns [`Err`] if there is not enough characters in current string view.
     ///
     /// ```rust
-    /// use string_view::StrExt;
+    /// use string_view::{Side, StrExt};
     ///
     /// let text = "One and only Hello World";
     ///
@@ -430,10 +490,10 @@ impl<'a> StringView<'a> {
     /// assert_eq!(view.as_str(), "World");
     ///
     /// let result = view.try_reduce_left(10);
-    /// assert!(matches!(result, Err(ViewIsTooShort)));
+    /// assert_eq!(result.unwrap_err().side(), Side::Left);
     /// assert_eq!(view.as_str(), "World");
     /// ```
-    pub fn try_reduce_left(&mut self, n: usize) -> Result<(), ViewIsTooShort<LEFT>> {
+    pub fn try_reduce_left(&mut self, n: usize) -> Result<(), ViewIsTooShort> {
         self.0.try_reduce_left(n)
     }
 
@@ -483,123 +543,78 @@ impl<'a> StringView<'a> {
     pub fn trim_while<F: FnMut(char) -> bool>(&mut self, func: F) {
         self.0.trim_while(func);
     }
-}
-
-impl Debug for StringView<'_> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        Debug::fmt(self.as_str(), f)
-    }
-}
-
-impl Display for StringView<'_> {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        Display::fmt(self.as_str(), f)
-    }
-}
-
-/// Mutable view into string slice.
-///
-/// Holds parent `str` info which allows to safely extend this view with parent
-/// size in mind.
-///
-/// ```rust
-/// use string_view::StrExt;
-///
-/// let mut text = String::from("Hello World");
-/// let mut view = text.view_part_mut(6, 11);
-/// assert_eq!(view.as_str(), "World");
-///
-/// view.extend_left(6);
-/// assert_eq!(view.as_str(), "Hello World");
-///
-/// view.reduce_right_while(char::is_alphabetic);
-/// assert_eq!(view.as_str(), "Hello ");
-/// ```
-///
-/// ### Convert to `&mut str` while in use:
-///
-/// ```rust
-/// use string_view::StrExt;
-///
-/// let mut text = String::from("Hello World");
-/// let mut view = text.view_part_mut(6, 11);
-/// assert_eq!(view.as_str(), "World");
-///
-/// view.as_str_mut().chars_in_place_mut().nth(3).unwrap().replace('L');
-/// assert_eq!(view.as_str(), "WorLd");
-/// assert_eq!(text, "Hello WorLd");
-///
-/// ```
-pub struct StringViewMut<'a>(View<&'a mut str>);
 
-impl<'a> StringViewMut<'a> {
-    /// Creates [`StringViewMut`] of a whole string slice.
+    /// Folds every character of this view into an accumulator by applying `func`.
     ///
-    /// see [`StringViewMut::new_part`] to view part of a string slice.
-    pub fn new(base: &'a mut str) -> Self {
-        Self(View {
-            view_len: base.len(),
-            base,
-            view_start: 0,
-        })
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "aé本";
+    /// let view = text.view();
+    ///
+    /// let len = view.fold_chars(0, |acc, ch| acc + ch.len_utf8());
+    /// assert_eq!(len, 6);
+    /// ```
+    pub fn fold_chars<B, F>(&self, init: B, func: F) -> B
+    where
+        F: FnMut(B, char) -> B,
+    {
+        self.0.fold_chars(init, func)
     }
 
-    /// Creates [`StringViewMut`] of a part of string slice using 2 byte indices.
+    /// Returns in-place characters iterator of this view together with byte indices
+    /// of the characters inside base string slice.
     ///
     /// ```rust
-    /// use string_view::StringViewMut;
+    /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World");
-    /// let mut view = StringViewMut::new_part(&mut text, 6, 11);
+    /// let text = "Hello World";
+    /// let view = text.view_part(6, 11);
     ///
-    /// assert_eq!(view.as_str(), "World");
+    /// let mut chars = view.char_indices_in_place();
+    ///
+    /// let (idx, ch) = chars.next().unwrap();
+    /// assert_eq!(idx, 6);
+    /// assert_eq!(ch, 'W');
+    ///
+    /// let (idx, ch) = chars.next_back().unwrap();
+    /// assert_eq!(idx, 10);
+    /// assert_eq!(ch, 'd');
     /// ```
+    pub fn char_indices_in_place(&self) -> CharIndicesInPlace<'a> {
+        CharIndicesInPlace::new(self.as_str(), self.start())
+    }
+
+    /// Returns in-place characters iterator of this view from right to left together
+    /// with byte indices of the characters inside base string slice.
     ///
-    /// Or using [`StrExt`](super::StrExt) extension trait:
+    /// Same as [`char_indices_in_place().rev()`](StringView::char_indices_in_place).
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World");
-    /// let mut view = text.view_part_mut(6, 11);
+    /// let text = "(Привет)";
+    /// let view = text.view_part(1, 13);
     ///
-    /// assert_eq!(view.as_str(), "World");
+    /// let mut chars = view.rchar_indices();
+    ///
+    /// let (idx, ch) = chars.next().unwrap();
+    /// assert_eq!(idx, 11);
+    /// assert_eq!(ch, 'т');
+    ///
+    /// let (idx, ch) = chars.next().unwrap();
+    /// assert_eq!(idx, 9);
+    /// assert_eq!(ch, 'е');
     /// ```
-    pub fn new_part(base: &'a mut str, view_start: usize, view_end: usize) -> Self {
-        assert!(
-            view_end >= view_start,
-            "View end index cannot be less than start index"
-        );
-        Self(View {
-            base,
-            view_start,
-            view_len: view_end - view_start,
-        })
-    }
-
-    /// Byte index of view start inside base String.
-    pub fn start(&self) -> usize {
-        self.0.start()
-    }
-
-    /// Byte index of view end inside base String.
-    pub fn end(&self) -> usize {
-        self.0.end()
-    }
-
-    pub fn as_str(&self) -> &str {
-        &self.0.base[self.0.view_start..self.0.view_start + self.0.view_len]
-    }
-
-    pub fn as_str_mut(&mut self) -> &mut str {
-        &mut self.0.base[self.0.view_start..self.0.view_start + self.0.view_len]
+    pub fn rchar_indices(&self) -> Rev<CharIndicesInPlace<'a>> {
+        self.char_indices_in_place().rev()
     }
 
-    /// Shrinks this view from the left to current right edge with length zero.
+    /// Extends string view to the left and to the right while `func` returns `true`.
     ///
     /// ```toml,ignore
-    /// [ str [ view ]  ]
-    /// [ str    -> []  ]
+    /// [ str     [ view ]      ]
+    /// [ str  [ <- view -> ]   ]
     /// ```
     ///
     /// #### Example:
@@ -607,454 +622,3234 @@ impl<'a> StringViewMut<'a> {
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World");
-    /// let mut view = text.view_mut();
-    /// assert_eq!(view.as_str(), "Hello World");
+    /// let text = "Hello World";
+    /// let mut view = text.view_part(2, 3);
+    /// assert_eq!(view.as_str(), "l");
     ///
-    /// view.shrink_to_right();
-    /// view.extend_left_while(char::is_alphabetic);
-    /// assert_eq!(view.as_str(), "World");
+    /// view.extend_both_while(char::is_alphabetic);
+    /// assert_eq!(view.as_str(), "Hello");
     /// ```
-    pub fn shrink_to_right(&mut self) {
-        self.0.shrink_to_right();
+    pub fn extend_both_while<F: FnMut(char) -> bool>(&mut self, func: F) {
+        self.0.extend_both_while(func);
     }
 
-    /// Shrinks this view from the right to current left edge with length zero.
+    /// Returns `true` if this view has length of zero bytes.
     ///
-    /// ```toml,ignore
-    /// [ str [ view ]  ]
-    /// [ str [] <-     ]
-    /// ```
+    /// ```rust
+    /// use string_view::StrExt;
     ///
-    /// #### Example:
+    /// let text = " \t World";
+    /// let mut view = text.view_part(0, 2);
+    /// assert!(!view.is_empty());
+    ///
+    /// view.shrink_to_left();
+    /// assert!(view.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if this view is empty or contains only whitespace characters.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World");
-    /// let mut view = text.view_mut();
-    /// assert_eq!(view.as_str(), "Hello World");
+    /// let text = " \t World";
+    /// let mut view = text.view_part(0, 2);
+    /// assert!(view.is_blank());
     ///
-    /// view.shrink_to_left();
-    /// view.extend_right_while(char::is_alphabetic);
-    /// assert_eq!(view.as_str(), "Hello");
+    /// view.extend_right(2);
+    /// assert!(!view.is_blank());
     /// ```
-    pub fn shrink_to_left(&mut self) {
-        self.0.shrink_to_left();
+    pub fn is_blank(&self) -> bool {
+        self.0.is_blank()
     }
 
-    /// Extend string view to the right by `n` characters.
+    /// Returns index of the first character which differs between windows of this and
+    /// `other` view, or `None` if they are equal character by character.
     ///
-    /// ```toml,ignore
-    /// [ str  [ view ]         ]
-    /// [ str  [  view  -> n ]  ]
+    /// If one window is a prefix of the other, index of the first character past the
+    /// shorter one is returned.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let old = "foobar".view();
+    /// let new = "foobaz".view();
+    ///
+    /// assert_eq!(old.first_difference(&new), Some(5));
+    /// assert_eq!(old.first_difference(&old), None);
     /// ```
+    pub fn first_difference(&self, other: &StringView) -> Option<usize> {
+        let mut this_chars = self.as_str().chars();
+        let mut other_chars = other.as_str().chars();
+
+        let mut idx = 0;
+        loop {
+            match (this_chars.next(), other_chars.next()) {
+                (None, None) => return None,
+                (this, other) if this == other => idx += 1,
+                _ => return Some(idx),
+            }
+        }
+    }
+
+    /// Returns byte length of the longest common prefix of windows of this and `other` view.
+    /// Returned length always lies on a character boundary of both windows.
     ///
-    /// panics if there is not enough characters in base string to the right of this view.
+    /// ```rust
+    /// use string_view::StrExt;
     ///
-    /// see [`StringView::try_extend_right`] for fallible version.
+    /// let text = "foo fob";
+    ///
+    /// let first = text.view_part(0, 3);
+    /// let second = text.view_part(4, 7);
+    ///
+    /// assert_eq!(first.common_prefix_byte_len(&second), 2);
+    /// ```
+    pub fn common_prefix_byte_len(&self, other: &StringView) -> usize {
+        self.as_str()
+            .chars()
+            .zip(other.as_str().chars())
+            .take_while(|(this, other)| this == other)
+            .map(|(ch, _)| ch.len_utf8())
+            .sum()
+    }
+
+    /// Returns `true` if this view contains at least `n` characters.
+    ///
+    /// Stops counting as soon as `n` characters are found.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World");
-    /// let mut view = text.view_part_mut(0, 5);
-    /// assert_eq!(view.as_str(), "Hello");
+    /// let text = "Привет Мир";
+    /// let view = text.view_part(13, 19);
+    /// assert_eq!(view.as_str(), "Мир");
     ///
-    /// view.extend_right(6);
-    /// assert_eq!(view.as_str(), "Hello World");
+    /// assert!(view.char_len_at_least(3));
+    /// assert!(!view.char_len_at_least(4));
     /// ```
-    pub fn extend_right(&mut self, n: usize) {
-        self.0.extend_right(n);
+    pub fn char_len_at_least(&self, n: usize) -> bool {
+        self.0.char_len_at_least(n)
     }
 
-    /// Try to extend string view to the right by `n` characters.
+    /// Returns `true` if this view contains exactly `n` characters.
     ///
-    /// ```toml,ignore
-    /// [ str  [ view ]         ]
-    /// [ str  [  view  -> n ]  ]
+    /// Stops counting as soon as more than `n` characters are found.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Привет Мир";
+    /// let view = text.view_part(13, 19);
+    /// assert_eq!(view.as_str(), "Мир");
+    ///
+    /// assert!(view.char_len_eq(3));
+    /// assert!(!view.char_len_eq(2));
     /// ```
+    pub fn char_len_eq(&self, n: usize) -> bool {
+        self.0.char_len_eq(n)
+    }
+
+    /// Reduces string view from left and right while characters are whitespace.
     ///
-    /// returns [`Err`] if there is not enough characters in base string to the right of this view.
+    /// Same as [`trim_while(char::is_whitespace)`](Self::trim_while).
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World");
+    /// let text = "[\n Hello \t]";
+    /// let mut view = text.view_part(1, 10);
     ///
-    /// let mut view = text.view_part_mut(0, 5);
+    /// view.trim();
     /// assert_eq!(view.as_str(), "Hello");
+    /// ```
+    pub fn trim(&mut self) {
+        self.0.trim_while(char::is_whitespace);
+    }
+
+    /// Reduces string view from the left while characters are whitespace.
     ///
-    /// let err = view.try_extend_right(4);
-    /// assert!(err.is_ok());
-    /// assert_eq!(view.as_str(), "Hello Wor");
+    /// ```rust
+    /// use string_view::StrExt;
     ///
-    /// let err = view.try_extend_right(10);
-    /// assert!(matches!(err, Err(BaseStringIsTooShort)));
-    /// assert_eq!(view.as_str(), "Hello Wor");
+    /// let text = "[\n Hello \t]";
+    /// let mut view = text.view_part(1, 10);
+    ///
+    /// view.trim_start();
+    /// assert_eq!(view.as_str(), "Hello \t");
     /// ```
-    pub fn try_extend_right(&mut self, n: usize) -> Result<(), BaseStringIsTooShort<RIGHT>> {
-        self.0.try_extend_right(n)
+    pub fn trim_start(&mut self) {
+        self.0.reduce_left_while(char::is_whitespace);
     }
 
-    /// Extend string view to the right while `func` returns `true`.
+    /// Reduces string view from the right while characters are whitespace.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "[\n Hello \t]";
+    /// let mut view = text.view_part(1, 10);
+    ///
+    /// view.trim_end();
+    /// assert_eq!(view.as_str(), "\n Hello");
+    /// ```
+    pub fn trim_end(&mut self) {
+        self.0.reduce_right_while(char::is_whitespace);
+    }
+
+    /// Moves this view to the right by `n` characters keeping its length in characters.
     ///
     /// ```toml,ignore
     /// [ str  [ view ]         ]
-    /// [ str  [  view  -> n ]  ]
+    /// [ str     -> [ view ]   ]
     /// ```
     ///
-    /// ### Example:
+    /// Stops at the end of base string. Returns number of characters view was moved by.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World !!!");
-    ///
-    /// let mut view = text.view_part_mut(0, 2);
-    /// assert_eq!(view.as_str(), "He");
+    /// let text = "abcdefghij";
+    /// let mut view = text.view_part(0, 5);
     ///
-    /// view.extend_right_while(|ch| ch != ' ');
-    /// assert_eq!(view.as_str(), "Hello");
+    /// assert_eq!(view.rotate_window_right(3), 3);
+    /// assert_eq!(view.as_str(), "defgh");
     ///
-    /// view.extend_right(1);
-    /// view.extend_right_while(|ch| ch != ' ');
-    /// assert_eq!(view.as_str(), "Hello World");
+    /// assert_eq!(view.rotate_window_right(3), 2);
+    /// assert_eq!(view.as_str(), "fghij");
     /// ```
-    pub fn extend_right_while<F>(&mut self, func: F)
-    where
-        F: FnMut(char) -> bool,
-    {
-        self.0.extend_right_while(func);
+    pub fn rotate_window_right(&mut self, n: usize) -> usize {
+        self.0.rotate_window_right(n)
     }
 
-    /// Reduce string view from the right by `n` characters.
+    /// Moves this view to the left by `n` characters keeping its length in characters.
     ///
     /// ```toml,ignore
-    /// [ str  [   view   ]    ]
-    /// [ str  [ view ] <- n   ]
+    /// [ str         [ view ]  ]
+    /// [ str  [ view ] <-      ]
     /// ```
     ///
-    /// panics if there is not enough characters in current string view.
+    /// Stops at the start of base string. Returns number of characters view was moved by.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World");
+    /// let text = "abcdefghij";
+    /// let mut view = text.view_part(0, 5);
+    /// view.rotate_window_right(5);
     ///
-    /// let mut view = text.view_mut();
-    /// assert_eq!(view.as_str(), "Hello World");
+    /// assert_eq!(view.rotate_window_left(3), 3);
+    /// assert_eq!(view.as_str(), "cdefg");
     ///
-    /// view.reduce_right(6);
+    /// assert_eq!(view.rotate_window_left(3), 2);
+    /// assert_eq!(view.as_str(), "abcde");
+    /// ```
+    pub fn rotate_window_left(&mut self, n: usize) -> usize {
+        self.0.rotate_window_left(n)
+    }
+
+    /// Returns `true` if window of this view starts with `prefix`.
+    ///
+    /// Characters of base string outside of the view are ignored.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "xHellox";
+    /// let view = text.view_part(1, 6);
     /// assert_eq!(view.as_str(), "Hello");
+    ///
+    /// assert!(view.starts_with("Hel"));
+    /// assert!(!view.starts_with("xHel"));
     /// ```
-    pub fn reduce_right(&mut self, n: usize) {
-        self.0.reduce_right(n);
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.0.as_str().starts_with(prefix)
     }
 
-    /// Try to reduce string view from the right by `n` characters.
+    /// Returns `true` if window of this view ends with `suffix`.
+    ///
+    /// Characters of base string outside of the view are ignored.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "xHellox";
+    /// let view = text.view_part(1, 6);
+    /// assert_eq!(view.as_str(), "Hello");
+    ///
+    /// assert!(view.ends_with("llo"));
+    /// assert!(!view.ends_with("llox"));
+    /// ```
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.0.as_str().ends_with(suffix)
+    }
+
+    /// Returns character of this view which ends at byte index `byte` of base string slice.
+    ///
+    /// Returns `None` if there is no such character inside the view or `byte` is not on a
+    /// char boundary.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Привет Мир";
+    /// let view = text.view_part(13, 19);
+    /// assert_eq!(view.as_str(), "Мир");
+    ///
+    /// assert_eq!(view.char_before(15).unwrap(), 'М');
+    /// assert_eq!(view.char_before(19).unwrap(), 'р');
+    ///
+    /// assert!(view.char_before(13).is_none()); // view edge
+    /// assert!(view.char_before(14).is_none()); // not a char boundary
+    /// ```
+    pub fn char_before(&self, byte: usize) -> Option<Char<'a>> {
+        if byte <= self.start() || byte > self.end() || !self.0.base.is_char_boundary(byte) {
+            return None;
+        }
+        CharsInPlace::new(&self.0.base[self.start()..byte]).next_back()
+    }
+
+    /// Returns character of this view which starts at byte index `byte` of base string slice.
+    ///
+    /// Returns `None` if there is no such character inside the view or `byte` is not on a
+    /// char boundary.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Привет Мир";
+    /// let view = text.view_part(13, 19);
+    /// assert_eq!(view.as_str(), "Мир");
+    ///
+    /// assert_eq!(view.char_after(13).unwrap(), 'М');
+    /// assert_eq!(view.char_after(17).unwrap(), 'р');
+    ///
+    /// assert!(view.char_after(19).is_none()); // view edge
+    /// assert!(view.char_after(14).is_none()); // not a char boundary
+    /// ```
+    pub fn char_after(&self, byte: usize) -> Option<Char<'a>> {
+        if byte < self.start() || byte >= self.end() || !self.0.base.is_char_boundary(byte) {
+            return None;
+        }
+        CharsInPlace::new(&self.0.base[byte..self.end()]).next()
+    }
+
+    /// Returns iterator over views of lines of this view split at every `\n`.
+    ///
+    /// Unlike [`str::lines`] behaves like [`str::split('\n')`](str::split): trailing `\n`
+    /// produces final empty view and `\r` is not removed from line ends. This allows to
+    /// restore original text exactly.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "a\nb\n";
+    /// let mut lines = text.view().split_lines();
+    ///
+    /// let line = lines.next().unwrap();
+    /// assert_eq!((line.as_str(), line.start()), ("a", 0));
+    ///
+    /// let line = lines.next().unwrap();
+    /// assert_eq!((line.as_str(), line.start()), ("b", 2));
+    ///
+    /// let line = lines.next().unwrap();
+    /// assert_eq!((line.as_str(), line.start()), ("", 4));
+    ///
+    /// assert!(lines.next().is_none());
+    /// ```
+    pub fn split_lines(&self) -> SplitLines<'a> {
+        SplitLines::new(self.0.base, self.start(), self.end())
+    }
+
+    /// Reduce string view to the characters from `start_char` (inclusive) to `end_char`
+    /// (exclusive), where indices are counted in characters of current string view.
     ///
     /// ```toml,ignore
-    /// [ str  [   view   ]    ]
-    /// [ str  [ view ] <- n   ]
+    /// [ str [ view [ chars ] view ] str ]
+    /// [ str        [ view  ]        str ]
     /// ```
     ///
-    /// returns [`Err`] if there is not enough characters in current string view.
+    /// returns [`Err`] if `start_char > end_char` or there is not enough characters in
+    /// current string view. String view is not modified in that case.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("One and only Hello World");
+    /// let text = "аб本гд";
     ///
-    /// let mut view = text.view_part_mut(13, 24);
-    /// assert_eq!(view.as_str(), "Hello World");
+    /// let mut view = text.view();
     ///
-    /// let result = view.try_reduce_right(6);
+    /// let result = view.reduce_to_char_range(1, 3);
     /// assert!(result.is_ok());
-    /// assert_eq!(view.as_str(), "Hello");
+    /// assert_eq!(view.as_str(), "б本");
     ///
-    /// let result = view.try_reduce_right(10);
-    /// assert!(matches!(result, Err(ViewIsTooShort)));
-    /// assert_eq!(view.as_str(), "Hello");
+    /// let result = view.reduce_to_char_range(1, 3);
+    /// assert!(result.is_err());
+    /// assert_eq!(view.as_str(), "б本");
     /// ```
-    pub fn try_reduce_right(&mut self, n: usize) -> Result<(), ViewIsTooShort<RIGHT>> {
-        self.0.try_reduce_right(n)
+    pub fn reduce_to_char_range(
+        &mut self,
+        start_char: usize,
+        end_char: usize,
+    ) -> Result<(), ViewIsTooShort> {
+        self.0.reduce_to_char_range(start_char, end_char)
     }
 
-    /// Reduce string view from the right while `func` returns `true`.
+    /// Returns the longest of `candidates` this string view starts with.
     ///
-    /// ```toml,ignore
-    /// [ str  [   view   ]    ]
-    /// [ str  [ view ] <- n   ]
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "<= x";
+    /// let view = text.view();
+    ///
+    /// assert_eq!(view.longest_prefix(&["<", "<=", "<<"]), Some("<="));
+    /// assert_eq!(view.longest_prefix(&[">", ">="]), None);
     /// ```
+    pub fn longest_prefix<'c>(&self, candidates: &[&'c str]) -> Option<&'c str> {
+        self.0.longest_prefix(candidates)
+    }
+
+    /// Writes characters of this string view into `buf` and returns number of characters
+    /// written. Only the first `buf.len()` characters are written if `buf` is too short.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World !!!");
+    /// let text = "Hello World";
+    /// let view = text.view_part(6, 11);
     ///
-    /// let mut view = text.view_mut();
-    /// assert_eq!(view.as_str(), "Hello World !!!");
+    /// let mut buf = ['\0'; 3];
+    /// assert_eq!(view.collect_chars_into(&mut buf), 3);
+    /// assert_eq!(buf, ['W', 'o', 'r']);
+    /// ```
+    pub fn collect_chars_into(&self, buf: &mut [char]) -> usize {
+        self.0.collect_chars_into(buf)
+    }
+
+    /// Approximate number of terminal columns this string view takes.
     ///
-    /// view.reduce_right_while(|ch| ch != ' ');
-    /// assert_eq!(view.as_str(), "Hello World ");
+    /// See [`StrExt::display_width`](crate::StrExt::display_width).
     ///
-    /// view.reduce_right(1);
-    /// view.reduce_right_while(|ch| ch != ' ');
-    /// assert_eq!(view.as_str(), "Hello ");
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let view = text.view_part(6, 11);
+    ///
+    /// assert_eq!(view.display_width(), 5);
     /// ```
-    pub fn reduce_right_while<F>(&mut self, func: F)
+    pub fn display_width(&self) -> usize {
+        self.0.display_width()
+    }
+
+    /// Returns iterator over consecutive views of this view, each one `sizes[i]` characters long.
+    /// Useful for fixed-width record formats.
+    ///
+    /// Iteration stops if there is not enough characters left in this view for the next field.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "2023-01-15";
+    ///
+    /// let fields: Vec<&str> = text
+    ///     .view()
+    ///     .split_n_chars(&[4, 1, 2, 1, 2])
+    ///     .map(|field| field.as_str())
+    ///     .collect();
+    ///
+    /// assert_eq!(fields, ["2023", "-", "01", "-", "15"]);
+    /// ```
+    pub fn split_n_chars<'s>(&self, sizes: &'s [usize]) -> SplitNChars<'a, 's> {
+        SplitNChars::new(self.0.base, self.start(), self.end(), sizes)
+    }
+
+    /// Splits this view at the first occurrence of `delim` into views before and after it,
+    /// excluding `delim` itself. Returns [`None`] if `delim` is not found.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "key=value";
+    ///
+    /// let (key, value) = text.view().split_once_view("=").unwrap();
+    /// assert_eq!((key.as_str(), key.start()), ("key", 0));
+    /// assert_eq!((value.as_str(), value.start()), ("value", 4));
+    ///
+    /// assert!(text.view().split_once_view(":").is_none());
+    /// ```
+    pub fn split_once_view(&self, delim: &str) -> Option<(StringView<'a>, StringView<'a>)> {
+        let delim_start = self.start() + self.as_str().find(delim)?;
+        let delim_end = delim_start + delim.len();
+
+        Some((
+            StringView::new_part(self.0.base, self.start(), delim_start),
+            StringView::new_part(self.0.base, delim_end, self.end()),
+        ))
+    }
+
+    /// Returns `i`-th byte of this string view, or [`None`] if `i` is out of range.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let view = text.view_part(6, 11);
+    ///
+    /// assert_eq!(view.byte(0), Some(b'W'));
+    /// assert_eq!(view.byte(5), None);
+    /// ```
+    pub fn byte(&self, i: usize) -> Option<u8> {
+        self.0.byte(i)
+    }
+
+    /// Replaces this view with the one returned by `func`, which allows to chain view
+    /// transformations. Returned view has to share base string with this view.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "name = value; other = 1";
+    ///
+    /// let value = text
+    ///     .view()
+    ///     .map_window(|view| view.split_once_view("; ").unwrap().0)
+    ///     .map_window(|view| view.split_once_view(" = ").unwrap().1);
+    ///
+    /// assert_eq!(value.as_str(), "value");
+    /// assert_eq!(value.start(), 7);
+    /// ```
+    pub fn map_window<F>(self, func: F) -> StringView<'a>
     where
-        F: FnMut(char) -> bool,
+        F: FnOnce(&StringView<'a>) -> StringView<'a>,
     {
-        self.0.reduce_right_while(func);
+        let mapped = func(&self);
+        debug_assert!(
+            core::ptr::eq(self.0.base, mapped.0.base),
+            "Mapped string view has to share base string with original one"
+        );
+        mapped
     }
 
-    /// Extend string view to the left by `n` characters.
+    /// Checks that this string view is an ASCII case-insensitive match with `other`.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "SELECT * FROM table";
+    /// let view = text.view_part(0, 6);
+    ///
+    /// assert!(view.eq_ignore_ascii_case("select"));
+    /// ```
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+
+    /// Creates new view of a part of this view using 2 byte indices relative to this view start.
     ///
     /// ```toml,ignore
-    /// [ str        [ view ]  ]
-    /// [ str  [ n <- view  ]  ]
+    /// [ str [ view [ sub ] view ] str ]
     /// ```
     ///
-    /// panics if there is not enough characters in base string to the left of this view.
+    /// **Panics** if indices are out of bounds of this view or do not lie on character boundaries.
     ///
-    /// see [`StringView::try_extend_left`] for fallible version.
+    /// see [`StringView::try_sub_view`] for fallible version.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World");
-    /// let mut view = text.view_part_mut(6, 11);
-    /// assert_eq!(view.as_str(), "World");
+    /// let text = "Hello World";
+    /// let view = text.view_part(6, 11);
     ///
-    /// view.extend_left(6);
-    /// assert_eq!(view.as_str(), "Hello World");
+    /// let sub = view.sub_view(1, 3);
+    /// assert_eq!(sub.as_str(), "or");
+    /// assert_eq!(sub.start(), 7);
     /// ```
-    pub fn extend_left(&mut self, n: usize) {
-        self.0.extend_left(n);
+    pub fn sub_view(&self, start: usize, end: usize) -> StringView<'a> {
+        self.try_sub_view(start, end)
+            .expect("Unable to create sub view of string view")
     }
 
-    /// Try to extend string view to the left by `n` characters.
+    /// Try to create new view of a part of this view using 2 byte indices relative to this view start.
     ///
     /// ```toml,ignore
-    /// [ str        [ view ]  ]
-    /// [ str  [ n <- view  ]  ]
+    /// [ str [ view [ sub ] view ] str ]
     /// ```
     ///
-    /// returns [`Err`] if there is not enough characters in base string to the right of this view.
+    /// returns [`Err`] if indices are out of bounds of this view or do not lie on character boundaries.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World");
-    ///
-    /// let mut view = text.view_part_mut(6, 11);
-    /// assert_eq!(view.as_str(), "World");
+    /// let text = "Привет World";
+    /// let view = text.view_part(0, 12);
     ///
-    /// let err = view.try_extend_left(4);
-    /// assert!(err.is_ok());
-    /// assert_eq!(view.as_str(), "llo World");
+    /// assert_eq!(view.try_sub_view(2, 6).unwrap().as_str(), "ри");
     ///
-    /// let err = view.try_extend_left(10);
-    /// assert!(matches!(err, Err(BaseStringIsTooShort)));
-    /// assert_eq!(view.as_str(), "llo World");
+    /// assert!(view.try_sub_view(1, 6).is_err());
+    /// assert!(view.try_sub_view(2, 14).is_err());
     /// ```
-    pub fn try_extend_left(&mut self, n: usize) -> Result<(), BaseStringIsTooShort<LEFT>> {
-        self.0.try_extend_left(n)
+    pub fn try_sub_view(&self, start: usize, end: usize) -> Result<StringView<'a>, InvalidRange> {
+        let view = self.as_str();
+
+        if start > end || !view.is_char_boundary(start) || !view.is_char_boundary(end) {
+            return Err(InvalidRange { start, end });
+        }
+        Ok(StringView::new_part(
+            self.0.base,
+            self.start() + start,
+            self.start() + end,
+        ))
     }
 
-    /// Extend string view to the left while `func` returns `true`.
+    /// Creates new view over the same base string slice with given byte offsets.
+    /// This view is not modified.
     ///
-    /// ```toml,ignore
-    /// [ str        [ view ]  ]
-    /// [ str  [ n <- view  ]  ]
+    /// **Panics** if offsets are out of bounds of base string slice or do not lie on
+    /// character boundaries.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let view = text.view_part(0, 5);
+    ///
+    /// let other = view.with_offsets(6, 5);
+    /// assert_eq!(other.as_str(), "World");
+    /// assert_eq!(view.as_str(), "Hello");
     /// ```
+    pub fn with_offsets(&self, view_start: usize, view_len: usize) -> StringView<'a> {
+        let view_end = view_start.checked_add(view_len).filter(|&end| {
+            self.0.base.is_char_boundary(view_start) && self.0.base.is_char_boundary(end)
+        });
+
+        let view_end = view_end.expect(
+            "Offsets have to be in bounds of base string slice and lie on character boundaries",
+        );
+        StringView::new_part(self.0.base, view_start, view_end)
+    }
+
+    /// Checks if this string view contains `pat`, which can be a [`char`] or a string slice.
+    /// Only current window is searched, not the base string slice.
     ///
-    /// #### Example:
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let mut view = text.view();
+    ///
+    /// assert!(view.contains('W'));
+    /// assert!(view.contains("World"));
+    ///
+    /// view.reduce_right(6);
+    /// assert!(!view.contains("World"));
+    /// ```
+    pub fn contains<P: ViewPattern>(&self, pat: P) -> bool {
+        pat.occurs_in(self.as_str())
+    }
+
+    /// Returns number of lines in this string view. Lines are counted the same way as
+    /// in [`str::lines`], so trailing line terminator does not start a new line.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World !!!");
+    /// let text = "one\ntwo\nthree\n";
+    /// let view = text.view_part(4, 14);
     ///
-    /// let mut view = text.view_part_mut(14, 15);
-    /// assert_eq!(view.as_str(), "!");
+    /// assert_eq!(view.as_str(), "two\nthree\n");
+    /// assert_eq!(view.line_count(), 2);
+    /// ```
+    pub fn line_count(&self) -> usize {
+        self.0.line_count()
+    }
+
+    /// Returns bytes of this string view.
     ///
-    /// view.extend_left_while(|ch| ch != ' ');
-    /// assert_eq!(view.as_str(), "!!!");
+    /// ```rust
+    /// use string_view::StrExt;
     ///
-    /// view.extend_left(1);
-    /// view.extend_left_while(|ch| ch != ' ');
-    /// assert_eq!(view.as_str(), "World !!!");
+    /// let text = "Hello World";
+    /// let view = text.view_part(6, 11);
+    ///
+    /// assert_eq!(view.as_bytes(), b"World");
     /// ```
-    pub fn extend_left_while<F>(&mut self, func: F)
-    where
-        F: FnMut(char) -> bool,
-    {
-        self.0.extend_left_while(func);
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
     }
 
-    /// Reduce string view from the left by `n` characters.
+    /// Extend string view to the right up to and including the first `delim` character.
     ///
     /// ```toml,ignore
-    /// [ str   [   view   ]   ]
-    /// [ str  n -> [ view ]   ]
+    /// [ str  [ view ]          delim ]
+    /// [ str  [  view  ->  delim ]    ]
     /// ```
     ///
-    /// panics if there is not enough characters in current string view.
-    ///
-    /// see [`StringView::try_reduce_left`] for fallible version.
+    /// returns `false` if `delim` was not found, in which case view is extended to the end
+    /// of base string slice.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("Hello World");
+    /// let text = "key=value\nnext";
+    /// let mut view = text.view_part(0, 0);
     ///
-    /// let mut view = text.view_mut();
-    /// assert_eq!(view.as_str(), "Hello World");
+    /// assert!(view.extend_right_to('\n'));
+    /// assert_eq!(view.as_str(), "key=value\n");
     ///
-    /// view.reduce_left(6);
-    /// assert_eq!(view.as_str(), "World");
+    /// assert!(!view.extend_right_to('\n'));
+    /// assert_eq!(view.as_str(), "key=value\nnext");
     /// ```
-    pub fn reduce_left(&mut self, n: usize) {
-        self.0.reduce_left(n);
+    pub fn extend_right_to(&mut self, delim: char) -> bool {
+        self.0.extend_right_to(delim)
     }
 
-    /// Try to reduce string view from the left by `n` characters.
+    /// Extend string view to the left up to and including the first `delim` character.
     ///
     /// ```toml,ignore
-    /// [ str   [   view   ]   ]
-    /// [ str  n -> [ view ]   ]
+    /// [ str delim          [ view ]  ]
+    /// [ str [ delim  <-  view     ]  ]
     /// ```
     ///
-    /// returns [`Err`] if there is not enough characters in current string view.
+    /// returns `false` if `delim` was not found, in which case view is extended to the start
+    /// of base string slice.
     ///
     /// ```rust
     /// use string_view::StrExt;
     ///
-    /// let mut text = String::from("One and only Hello World");
-    ///
-    /// let mut view = text.view_part_mut(13, 24);
-    /// assert_eq!(view.as_str(), "Hello World");
+    /// let text = "key=value\nnext";
+    /// let mut view = text.view_part(9, 9);
     ///
-    /// let result = view.try_reduce_left(6);
-    /// assert!(result.is_ok());
-    /// assert_eq!(view.as_str(), "World");
+    /// assert!(view.extend_left_to('='));
+    /// assert_eq!(view.as_str(), "=value");
     ///
-    /// let result = view.try_reduce_left(10);
-    /// assert!(matches!(result, Err(ViewIsTooShort)));
+    /// assert!(!view.extend_left_to('='));
+    /// assert_eq!(view.as_str(), "key=value");
+    /// ```
+    pub fn extend_left_to(&mut self, delim: char) -> bool {
+        self.0.extend_left_to(delim)
+    }
+
+    /// Extend string view to the right while `func` returns `true`. First argument of `func`
+    /// is byte offset of the character relative to current view start.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]         ]
+    /// [ str  [  view  -> n ]  ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let mut view = text.view_part(0, 1);
+    ///
+    /// // Extend while alphabetic, but at most 4 bytes long
+    /// view.extend_right_while_indexed(|idx, ch| idx < 4 && ch.is_alphabetic());
+    /// assert_eq!(view.as_str(), "Hell");
+    /// ```
+    pub fn extend_right_while_indexed<F>(&mut self, func: F)
+    where
+        F: FnMut(usize, char) -> bool,
+    {
+        self.0.extend_right_while_indexed(func);
+    }
+
+    /// Reduce string view from the right while `func` returns `true`. First argument of `func`
+    /// is byte offset of the character relative to current view start.
+    ///
+    /// ```toml,ignore
+    /// [ str  [   view   ]  ]
+    /// [ str  [ view ] <- n ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let mut view = text.view_part(0, 11);
+    ///
+    /// // Keep at least 7 bytes
+    /// view.reduce_right_while_indexed(|idx, ch| idx >= 7 && ch != ' ');
+    /// assert_eq!(view.as_str(), "Hello W");
+    /// ```
+    pub fn reduce_right_while_indexed<F>(&mut self, func: F)
+    where
+        F: FnMut(usize, char) -> bool,
+    {
+        self.0.reduce_right_while_indexed(func);
+    }
+
+    /// Reduce string view from the left while `func` returns `true`. First argument of `func`
+    /// is byte offset of the character relative to current view start.
+    ///
+    /// ```toml,ignore
+    /// [ str   [   view   ]   ]
+    /// [ str  n -> [ view ]   ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let mut view = text.view_part(0, 11);
+    ///
+    /// // Skip at most 3 bytes
+    /// view.reduce_left_while_indexed(|idx, ch| idx < 3 && ch != ' ');
+    /// assert_eq!(view.as_str(), "lo World");
+    /// ```
+    pub fn reduce_left_while_indexed<F>(&mut self, func: F)
+    where
+        F: FnMut(usize, char) -> bool,
+    {
+        self.0.reduce_left_while_indexed(func);
+    }
+
+    /// Extend string view to the right by the smallest number of characters needed for it
+    /// to contain `needle`.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]            ]
+    /// [ str  [  view  needle ]   ]
+    /// ```
+    ///
+    /// returns [`Err`] if base string slice doesn't contain `needle` to the right of view
+    /// start. String view is not modified in that case.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "<a>text</a>";
+    /// let mut view = text.view_part(0, 0);
+    ///
+    /// assert!(view.extend_right_until_contains("</a>").is_ok());
+    /// assert_eq!(view.as_str(), "<a>text</a>");
+    ///
+    /// assert!(view.extend_right_until_contains("</b>").is_err());
+    /// ```
+    pub fn extend_right_until_contains(
+        &mut self,
+        needle: &str,
+    ) -> Result<(), BaseStringIsTooShort> {
+        self.0.extend_right_until_contains(needle)
+    }
+
+    /// Extend string view to the right by `n` characters or up to the end of base string
+    /// slice if there are less. Returns number of characters view was extended by.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]         ]
+    /// [ str  [  view  -> n ]  ]
+    /// ```
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let mut view = text.view_part(0, 5);
+    ///
+    /// assert_eq!(view.extend_right_saturating(10), 6);
+    /// assert_eq!(view.as_str(), "Hello World");
+    /// ```
+    pub fn extend_right_saturating(&mut self, n: usize) -> usize {
+        self.0.extend_right_saturating(n)
+    }
+
+    /// Extend string view to the left by `n` characters or up to the start of base string
+    /// slice if there are less. Returns number of characters view was extended by.
+    ///
+    /// ```toml,ignore
+    /// [ str         [ view ]  ]
+    /// [ str  [ n <- view   ]  ]
+    /// ```
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let mut view = text.view_part(6, 11);
+    ///
+    /// assert_eq!(view.extend_left_saturating(10), 6);
+    /// assert_eq!(view.as_str(), "Hello World");
+    /// ```
+    pub fn extend_left_saturating(&mut self, n: usize) -> usize {
+        self.0.extend_left_saturating(n)
+    }
+
+    /// Reduce string view from the right by `n` characters or to length zero if there are
+    /// less. Returns number of characters view was reduced by.
+    ///
+    /// ```toml,ignore
+    /// [ str  [   view   ]  ]
+    /// [ str  [ view ] <- n ]
+    /// ```
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let mut view = text.view_part(0, 5);
+    ///
+    /// assert_eq!(view.reduce_right_saturating(10), 5);
+    /// assert_eq!(view.as_str(), "");
+    /// ```
+    pub fn reduce_right_saturating(&mut self, n: usize) -> usize {
+        self.0.reduce_right_saturating(n)
+    }
+
+    /// Reduce string view from the left by `n` characters or to length zero if there are
+    /// less. Returns number of characters view was reduced by.
+    ///
+    /// ```toml,ignore
+    /// [ str   [   view   ]   ]
+    /// [ str  n -> [ view ]   ]
+    /// ```
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let mut view = text.view_part(6, 11);
+    ///
+    /// assert_eq!(view.reduce_left_saturating(10), 5);
+    /// assert_eq!((view.as_str(), view.start()), ("", 11));
+    /// ```
+    pub fn reduce_left_saturating(&mut self, n: usize) -> usize {
+        self.0.reduce_left_saturating(n)
+    }
+
+    /// Returns index of the character of this string view displayed at terminal `column`,
+    /// or [`None`] if view is shorter.
+    ///
+    /// Every character is assumed to take a single column. With `width` feature enabled
+    /// width of every character is measured using `unicode-width`, see
+    /// [`StrExt::display_width`](crate::StrExt::display_width).
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "hello";
+    /// let view = text.view();
+    ///
+    /// assert_eq!(view.char_idx_at_column(3), Some(3));
+    /// assert_eq!(view.char_idx_at_column(5), None);
+    /// ```
+    pub fn char_idx_at_column(&self, column: usize) -> Option<usize> {
+        self.0.char_idx_at_column(column)
+    }
+
+    /// Number of bytes debug-escaped form of this string view takes,
+    /// i.e. sum of [`char::escape_debug`] lengths of its characters in bytes.
+    ///
+    /// Useful to allocate buffer of exact size before escaping.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "[a\tb]";
+    /// let view = text.view_part(1, 4);
+    ///
+    /// assert_eq!(view.escape_debug_len(), 4); // a\tb
+    /// ```
+    pub fn escape_debug_len(&self) -> usize {
+        self.0.escape_debug_len()
+    }
+
+    /// Calls `func` with every character of this view together with its byte index
+    /// inside base string slice.
+    ///
+    /// Same as [`char_indices_in_place().for_each(...)`](StringView::char_indices_in_place).
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello Мир";
+    /// let view = text.view_part(6, 12);
+    ///
+    /// let mut offsets = Vec::new();
+    /// view.for_each_char_offset(|offset, ch| offsets.push((offset, ch.char())));
+    ///
+    /// assert_eq!(offsets, [(6, 'М'), (8, 'и'), (10, 'р')]);
+    /// ```
+    pub fn for_each_char_offset<F: FnMut(usize, Char<'a>)>(&self, mut func: F) {
+        let mut offset = self.start();
+
+        for ch in CharsInPlace::new(self.as_str()) {
+            let char_len = ch.len_utf8();
+            func(offset, ch);
+            offset += char_len;
+        }
+    }
+
+    /// Copies contents of this view into new [`String`]. Requires `alloc` feature.
+    ///
+    /// [`ToString`](alloc::string::ToString) is also available through [`Display`] implementation.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "Hello World";
+    /// let view = text.view_part(0, 5);
+    ///
+    /// assert_eq!(view.into_owned(), "Hello");
+    /// assert_eq!(text.view_part(6, 11).to_string(), "World");
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn into_owned(self) -> String {
+        String::from(self)
+    }
+
+    /// Checks that characters of this string view match characters of `other` pairwise
+    /// using `cmp`. Returns `false` if they have different number of characters.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let text = "id: a1b";
+    /// let view = text.view_part(4, 7);
+    ///
+    /// let same_kind = |a: char, b: char| a == b || a.is_ascii_digit() && b.is_ascii_digit();
+    ///
+    /// assert!(view.chars_eq_by("a9b", same_kind));
+    /// assert!(!view.chars_eq_by("a9", same_kind));
+    /// ```
+    pub fn chars_eq_by<F: FnMut(char, char) -> bool>(&self, other: &str, cmp: F) -> bool {
+        self.0.chars_eq_by(other, cmp)
+    }
+}
+
+impl Debug for StringView<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for StringView<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for StringView<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for StringView<'_> {}
+
+impl PartialOrd for StringView<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StringView<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for StringView<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl AsRef<str> for StringView<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for StringView<'_> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Serializes contents of string view as a plain string. Requires `serde` feature.
+///
+/// `Deserialize` is not implemented: view has to borrow its base string slice, which
+/// deserializer is generally unable to provide (e.g. for strings with escape sequences).
+/// Deserialize into `&str` or `String` and create a view from it instead.
+///
+/// ```rust
+/// use string_view::StrExt;
+///
+/// let text = "Hello World";
+/// let view = text.view_part(6, 11);
+///
+/// assert_eq!(serde_json::to_string(&view).unwrap(), r#""World""#);
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for StringView<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Same as [`StringView::new`].
+///
+/// ```rust
+/// use string_view::StringView;
+///
+/// let view = StringView::from("Hello");
+/// assert_eq!(view.as_str(), "Hello");
+/// ```
+impl<'a> From<&'a str> for StringView<'a> {
+    fn from(base: &'a str) -> Self {
+        StringView::new(base)
+    }
+}
+
+/// Copies contents of string view into new [`String`]. Requires `alloc` feature.
+///
+/// ```rust
+/// use string_view::StrExt;
+///
+/// let text = "Hello World";
+///
+/// let owned = String::from(text.view_part(6, 11));
+/// assert_eq!(owned, "World");
+/// ```
+#[cfg(feature = "alloc")]
+impl From<StringView<'_>> for String {
+    fn from(view: StringView<'_>) -> Self {
+        String::from(view.as_str())
+    }
+}
+
+/// Iterates over characters of string view in-place.
+///
+/// ```rust
+/// use string_view::StrExt;
+///
+/// let text = "Hello World";
+///
+/// for ch in text.view_part(6, 11) {
+///     assert!(ch.char().is_alphabetic());
+/// }
+/// ```
+impl<'a> IntoIterator for StringView<'a> {
+    type Item = Char<'a>;
+    type IntoIter = CharsInPlace<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CharsInPlace::new(self.as_str())
+    }
+}
+
+impl<'a> IntoIterator for &StringView<'a> {
+    type Item = Char<'a>;
+    type IntoIter = CharsInPlace<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CharsInPlace::new(self.as_str())
+    }
+}
+
+/// Mutable view into string slice.
+///
+/// Holds parent `str` info which allows to safely extend this view with parent
+/// size in mind.
+///
+/// ```rust
+/// use string_view::StrExt;
+///
+/// let mut text = String::from("Hello World");
+/// let mut view = text.view_part_mut(6, 11);
+/// assert_eq!(view.as_str(), "World");
+///
+/// view.extend_left(6);
+/// assert_eq!(view.as_str(), "Hello World");
+///
+/// view.reduce_right_while(char::is_alphabetic);
+/// assert_eq!(view.as_str(), "Hello ");
+/// ```
+///
+/// ### Convert to `&mut str` while in use:
+///
+/// ```rust
+/// use string_view::StrExt;
+///
+/// let mut text = String::from("Hello World");
+/// let mut view = text.view_part_mut(6, 11);
+/// assert_eq!(view.as_str(), "World");
+///
+/// view.as_str_mut().chars_in_place_mut().nth(3).unwrap().replace('L');
+/// assert_eq!(view.as_str(), "WorLd");
+/// assert_eq!(text, "Hello WorLd");
+///
+/// ```
+pub struct StringViewMut<'a>(View<&'a mut str>);
+
+impl<'a> StringViewMut<'a> {
+    /// Creates [`StringViewMut`] of a whole string slice.
+    ///
+    /// see [`StringViewMut::new_part`] to view part of a string slice.
+    pub fn new(base: &'a mut str) -> Self {
+        Self(View {
+            view_len: base.len(),
+            base,
+            view_start: 0,
+        })
+    }
+
+    /// Creates [`StringViewMut`] of a part of string slice using 2 byte indices.
+    ///
+    /// ```rust
+    /// use string_view::StringViewMut;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = StringViewMut::new_part(&mut text, 6, 11);
+    ///
+    /// assert_eq!(view.as_str(), "World");
+    /// ```
+    ///
+    /// Or using [`StrExt`](super::StrExt) extension trait:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// assert_eq!(view.as_str(), "World");
+    /// ```
+    pub fn new_part(base: &'a mut str, view_start: usize, view_end: usize) -> Self {
+        assert!(
+            view_end >= view_start,
+            "View end index cannot be less than start index"
+        );
+        Self(View {
+            base,
+            view_start,
+            view_len: view_end - view_start,
+        })
+    }
+
+    /// Byte index of view start inside base String.
+    pub fn start(&self) -> usize {
+        self.0.start()
+    }
+
+    /// Byte index of view end inside base String.
+    pub fn end(&self) -> usize {
+        self.0.end()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0.base[self.0.view_start..self.0.view_start + self.0.view_len]
+    }
+
+    pub fn as_str_mut(&mut self) -> &mut str {
+        &mut self.0.base[self.0.view_start..self.0.view_start + self.0.view_len]
+    }
+
+    /// Shrinks this view from the left to current right edge with length zero.
+    ///
+    /// ```toml,ignore
+    /// [ str [ view ]  ]
+    /// [ str    -> []  ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_mut();
+    /// assert_eq!(view.as_str(), "Hello World");
+    ///
+    /// view.shrink_to_right();
+    /// view.extend_left_while(char::is_alphabetic);
+    /// assert_eq!(view.as_str(), "World");
+    /// ```
+    pub fn shrink_to_right(&mut self) {
+        self.0.shrink_to_right();
+    }
+
+    /// Shrinks this view from the right to current left edge with length zero.
+    ///
+    /// ```toml,ignore
+    /// [ str [ view ]  ]
+    /// [ str [] <-     ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_mut();
+    /// assert_eq!(view.as_str(), "Hello World");
+    ///
+    /// view.shrink_to_left();
+    /// view.extend_right_while(char::is_alphabetic);
+    /// assert_eq!(view.as_str(), "Hello");
+    /// ```
+    pub fn shrink_to_left(&mut self) {
+        self.0.shrink_to_left();
+    }
+
+    /// Shrinks this view to length zero at current start. Same as [`StringViewMut::shrink_to_left`].
+    ///
+    /// ```toml,ignore
+    /// [ str [ view ]  ]
+    /// [ str [] <-     ]
+    /// ```
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// view.shrink_to_empty_at_start();
+    /// assert_eq!(view.as_str(), "");
+    /// assert_eq!(view.start(), 6);
+    /// ```
+    pub fn shrink_to_empty_at_start(&mut self) {
+        self.0.shrink_to_left();
+    }
+
+    /// Shrinks this view to length zero at current start and returns string slice
+    /// which was removed from the view.
+    ///
+    /// ```toml,ignore
+    /// [ str [ view ]  ]
+    /// [ str [] <-     ]
+    /// ```
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// assert_eq!(view.collapse(), "World");
+    /// assert_eq!(view.as_str(), "");
+    /// assert_eq!(view.start(), 6);
+    /// ```
+    pub fn collapse(&mut self) -> &str {
+        let (start, end) = (self.start(), self.end());
+        self.0.shrink_to_left();
+        &self.0.base[start..end]
+    }
+
+    /// Extend string view to the right by `n` characters.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]         ]
+    /// [ str  [  view  -> n ]  ]
+    /// ```
+    ///
+    /// panics if there is not enough characters in base string to the right of this view.
+    ///
+    /// see [`StringView::try_extend_right`] for fallible version.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(0, 5);
+    /// assert_eq!(view.as_str(), "Hello");
+    ///
+    /// view.extend_right(6);
+    /// assert_eq!(view.as_str(), "Hello World");
+    /// ```
+    pub fn extend_right(&mut self, n: usize) {
+        self.0.extend_right(n);
+    }
+
+    /// Try to extend string view to the right by `n` characters.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]         ]
+    /// [ str  [  view  -> n ]  ]
+    /// ```
+    ///
+    /// returns [`Err`] if there is not enough characters in base string to the right of this view.
+    ///
+    /// ```rust
+    /// use string_view::{Side, StrExt};
+    ///
+    /// let mut text = String::from("Hello World");
+    ///
+    /// let mut view = text.view_part_mut(0, 5);
+    /// assert_eq!(view.as_str(), "Hello");
+    ///
+    /// let err = view.try_extend_right(4);
+    /// assert!(err.is_ok());
+    /// assert_eq!(view.as_str(), "Hello Wor");
+    ///
+    /// let err = view.try_extend_right(10);
+    /// assert_eq!(err.unwrap_err().side(), Side::Right);
+    /// assert_eq!(view.as_str(), "Hello Wor");
+    /// ```
+    pub fn try_extend_right(&mut self, n: usize) -> Result<(), BaseStringIsTooShort> {
+        self.0.try_extend_right(n)
+    }
+
+    /// Extend string view to the right while `func` returns `true`.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]         ]
+    /// [ str  [  view  -> n ]  ]
+    /// ```
+    ///
+    /// ### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World !!!");
+    ///
+    /// let mut view = text.view_part_mut(0, 2);
+    /// assert_eq!(view.as_str(), "He");
+    ///
+    /// view.extend_right_while(|ch| ch != ' ');
+    /// assert_eq!(view.as_str(), "Hello");
+    ///
+    /// view.extend_right(1);
+    /// view.extend_right_while(|ch| ch != ' ');
+    /// assert_eq!(view.as_str(), "Hello World");
+    /// ```
+    pub fn extend_right_while<F>(&mut self, func: F)
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.0.extend_right_while(func);
+    }
+
+    /// Reduce string view from the right by `n` characters.
+    ///
+    /// ```toml,ignore
+    /// [ str  [   view   ]    ]
+    /// [ str  [ view ] <- n   ]
+    /// ```
+    ///
+    /// panics if there is not enough characters in current string view.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    ///
+    /// let mut view = text.view_mut();
+    /// assert_eq!(view.as_str(), "Hello World");
+    ///
+    /// view.reduce_right(6);
+    /// assert_eq!(view.as_str(), "Hello");
+    /// ```
+    pub fn reduce_right(&mut self, n: usize) {
+        self.0.reduce_right(n);
+    }
+
+    /// Try to reduce string view from the right by `n` characters.
+    ///
+    /// ```toml,ignore
+    /// [ str  [   view   ]    ]
+    /// [ str  [ view ] <- n   ]
+    /// ```
+    ///
+    /// returns [`Err`] if there is not enough characters in current string view.
+    ///
+    /// ```rust
+    /// use string_view::{Side, StrExt};
+    ///
+    /// let mut text = String::from("One and only Hello World");
+    ///
+    /// let mut view = text.view_part_mut(13, 24);
+    /// assert_eq!(view.as_str(), "Hello World");
+    ///
+    /// let result = view.try_reduce_right(6);
+    /// assert!(result.is_ok());
+    /// assert_eq!(view.as_str(), "Hello");
+    ///
+    /// let result = view.try_reduce_right(10);
+    /// assert_eq!(result.unwrap_err().side(), Side::Right);
+    /// assert_eq!(view.as_str(), "Hello");
+    /// ```
+    pub fn try_reduce_right(&mut self, n: usize) -> Result<(), ViewIsTooShort> {
+        self.0.try_reduce_right(n)
+    }
+
+    /// Reduce string view from the right while `func` returns `true`.
+    ///
+    /// ```toml,ignore
+    /// [ str  [   view   ]    ]
+    /// [ str  [ view ] <- n   ]
+    /// ```
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World !!!");
+    ///
+    /// let mut view = text.view_mut();
+    /// assert_eq!(view.as_str(), "Hello World !!!");
+    ///
+    /// view.reduce_right_while(|ch| ch != ' ');
+    /// assert_eq!(view.as_str(), "Hello World ");
+    ///
+    /// view.reduce_right(1);
+    /// view.reduce_right_while(|ch| ch != ' ');
+    /// assert_eq!(view.as_str(), "Hello ");
+    /// ```
+    pub fn reduce_right_while<F>(&mut self, func: F)
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.0.reduce_right_while(func);
+    }
+
+    /// Extend string view to the left by `n` characters.
+    ///
+    /// ```toml,ignore
+    /// [ str        [ view ]  ]
+    /// [ str  [ n <- view  ]  ]
+    /// ```
+    ///
+    /// panics if there is not enough characters in base string to the left of this view.
+    ///
+    /// see [`StringView::try_extend_left`] for fallible version.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    /// assert_eq!(view.as_str(), "World");
+    ///
+    /// view.extend_left(6);
+    /// assert_eq!(view.as_str(), "Hello World");
+    /// ```
+    pub fn extend_left(&mut self, n: usize) {
+        self.0.extend_left(n);
+    }
+
+    /// Try to extend string view to the left by `n` characters.
+    ///
+    /// ```toml,ignore
+    /// [ str        [ view ]  ]
+    /// [ str  [ n <- view  ]  ]
+    /// ```
+    ///
+    /// returns [`Err`] if there is not enough characters in base string to the right of this view.
+    ///
+    /// ```rust
+    /// use string_view::{Side, StrExt};
+    ///
+    /// let mut text = String::from("Hello World");
+    ///
+    /// let mut view = text.view_part_mut(6, 11);
+    /// assert_eq!(view.as_str(), "World");
+    ///
+    /// let err = view.try_extend_left(4);
+    /// assert!(err.is_ok());
+    /// assert_eq!(view.as_str(), "llo World");
+    ///
+    /// let err = view.try_extend_left(10);
+    /// assert_eq!(err.unwrap_err().side(), Side::Left);
+    /// assert_eq!(view.as_str(), "llo World");
+    /// ```
+    pub fn try_extend_left(&mut self, n: usize) -> Result<(), BaseStringIsTooShort> {
+        self.0.try_extend_left(n)
+    }
+
+    /// Extend string view to the left while `func` returns `true`.
+    ///
+    /// ```toml,ignore
+    /// [ str        [ view ]  ]
+    /// [ str  [ n <- view  ]  ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World !!!");
+    ///
+    /// let mut view = text.view_part_mut(14, 15);
+    /// assert_eq!(view.as_str(), "!");
+    ///
+    /// view.extend_left_while(|ch| ch != ' ');
+    /// assert_eq!(view.as_str(), "!!!");
+    ///
+    /// view.extend_left(1);
+    /// view.extend_left_while(|ch| ch != ' ');
+    /// assert_eq!(view.as_str(), "World !!!");
+    /// ```
+    pub fn extend_left_while<F>(&mut self, func: F)
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.0.extend_left_while(func);
+    }
+
+    /// Reduce string view from the left by `n` characters.
+    ///
+    /// ```toml,ignore
+    /// [ str   [   view   ]   ]
+    /// [ str  n -> [ view ]   ]
+    /// ```
+    ///
+    /// panics if there is not enough characters in current string view.
+    ///
+    /// see [`StringView::try_reduce_left`] for fallible version.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    ///
+    /// let mut view = text.view_mut();
+    /// assert_eq!(view.as_str(), "Hello World");
+    ///
+    /// view.reduce_left(6);
+    /// assert_eq!(view.as_str(), "World");
+    /// ```
+    pub fn reduce_left(&mut self, n: usize) {
+        self.0.reduce_left(n);
+    }
+
+    /// Try to reduce string view from the left by `n` characters.
+    ///
+    /// ```toml,ignore
+    /// [ str   [   view   ]   ]
+    /// [ str  n -> [ view ]   ]
+    /// ```
+    ///
+    /// returns [`Err`] if there is not enough characters in current string view.
+    ///
+    /// ```rust
+    /// use string_view::{Side, StrExt};
+    ///
+    /// let mut text = String::from("One and only Hello World");
+    ///
+    /// let mut view = text.view_part_mut(13, 24);
+    /// assert_eq!(view.as_str(), "Hello World");
+    ///
+    /// let result = view.try_reduce_left(6);
+    /// assert!(result.is_ok());
     /// assert_eq!(view.as_str(), "World");
+    ///
+    /// let result = view.try_reduce_left(10);
+    /// assert_eq!(result.unwrap_err().side(), Side::Left);
+    /// assert_eq!(view.as_str(), "World");
+    /// ```
+    pub fn try_reduce_left(&mut self, n: usize) -> Result<(), ViewIsTooShort> {
+        self.0.try_reduce_left(n)
+    }
+
+    /// Reduce string view from the left while `func` returns `true`.
+    ///
+    /// ```toml,ignore
+    /// [ str   [   view   ]   ]
+    /// [ str  n -> [ view ]   ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World !!!");
+    ///
+    /// let mut view = text.view_mut();
+    /// assert_eq!(view.as_str(), "Hello World !!!");
+    ///
+    /// view.reduce_left_while(|ch| ch != ' ');
+    /// assert_eq!(view.as_str(), " World !!!");
+    ///
+    /// view.reduce_left(1);
+    /// view.reduce_left_while(|ch| ch != ' ');
+    /// assert_eq!(view.as_str(), " !!!");
+    /// ```
+    pub fn reduce_left_while<F>(&mut self, func: F)
+    where
+        F: FnMut(char) -> bool,
+    {
+        self.0.reduce_left_while(func);
+    }
+
+    /// Reduces string view from left and right while `func` returns `true`
+    ///
+    /// ```
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("  \n   Hello  \n \t  ");
+    /// let mut view = text.view_mut();
+    ///
+    /// view.trim_while(char::is_whitespace);
+    ///
+    /// assert_eq!(view.as_str(), "Hello");
+    /// ```
+    pub fn trim_while<F: FnMut(char) -> bool>(&mut self, func: F) {
+        self.0.trim_while(func);
+    }
+
+    /// Reduces string view from left and right while characters match `pat`.
+    ///
+    /// Same as [`trim_while`](Self::trim_while). Unlike [`StrExt::trim_matches_mut`](crate::StrExt::trim_matches_mut)
+    /// narrows this view instead of returning a detached string slice, so it can be extended back.
+    ///
+    /// ```
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("[--hi--]");
+    /// let mut view = text.view_part_mut(1, 7);
+    ///
+    /// view.trim_matches_mut(|ch| ch == '-');
+    /// assert_eq!(view.as_str(), "hi");
+    ///
+    /// view.extend_left(3);
+    /// assert_eq!(view.as_str(), "[--hi");
+    /// ```
+    pub fn trim_matches_mut<P: FnMut(char) -> bool>(&mut self, pat: P) {
+        self.trim_while(pat);
+    }
+
+    /// Folds every character of this view into an accumulator by applying `func`.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("aé本");
+    /// let view = text.view_mut();
+    ///
+    /// let len = view.fold_chars(0, |acc, ch| acc + ch.len_utf8());
+    /// assert_eq!(len, 6);
+    /// ```
+    pub fn fold_chars<B, F>(&self, init: B, func: F) -> B
+    where
+        F: FnMut(B, char) -> B,
+    {
+        self.0.fold_chars(init, func)
+    }
+
+    /// Extends string view to the left and to the right while `func` returns `true`.
+    ///
+    /// ```toml,ignore
+    /// [ str     [ view ]      ]
+    /// [ str  [ <- view -> ]   ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(2, 3);
+    /// assert_eq!(view.as_str(), "l");
+    ///
+    /// view.extend_both_while(char::is_alphabetic);
+    /// assert_eq!(view.as_str(), "Hello");
+    /// ```
+    pub fn extend_both_while<F: FnMut(char) -> bool>(&mut self, func: F) {
+        self.0.extend_both_while(func);
+    }
+
+    /// Returns `true` if this view has length of zero bytes.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from(" \t World");
+    /// let mut view = text.view_part_mut(0, 2);
+    /// assert!(!view.is_empty());
+    ///
+    /// view.shrink_to_left();
+    /// assert!(view.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns `true` if this view is empty or contains only whitespace characters.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from(" \t World");
+    /// let mut view = text.view_part_mut(0, 2);
+    /// assert!(view.is_blank());
+    ///
+    /// view.extend_right(2);
+    /// assert!(!view.is_blank());
+    /// ```
+    pub fn is_blank(&self) -> bool {
+        self.0.is_blank()
+    }
+
+    /// Consumes this view and returns it with leading and trailing whitespace removed
+    /// from the window. Returned view still holds the whole base string slice.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("  Hello World  ");
+    ///
+    /// let mut view = text.view_mut().into_trimmed();
+    /// assert_eq!(view.as_str(), "Hello World");
+    ///
+    /// view.extend_left(1);
+    /// assert_eq!(view.as_str(), " Hello World");
+    /// ```
+    pub fn into_trimmed(mut self) -> StringViewMut<'a> {
+        self.trim();
+        self
+    }
+
+    /// Returns `true` if this view contains at least `n` characters.
+    ///
+    /// Stops counting as soon as `n` characters are found.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Привет Мир");
+    /// let view = text.view_part_mut(13, 19);
+    /// assert_eq!(view.as_str(), "Мир");
+    ///
+    /// assert!(view.char_len_at_least(3));
+    /// assert!(!view.char_len_at_least(4));
+    /// ```
+    pub fn char_len_at_least(&self, n: usize) -> bool {
+        self.0.char_len_at_least(n)
+    }
+
+    /// Returns `true` if this view contains exactly `n` characters.
+    ///
+    /// Stops counting as soon as more than `n` characters are found.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Привет Мир");
+    /// let view = text.view_part_mut(13, 19);
+    /// assert_eq!(view.as_str(), "Мир");
+    ///
+    /// assert!(view.char_len_eq(3));
+    /// assert!(!view.char_len_eq(2));
+    /// ```
+    pub fn char_len_eq(&self, n: usize) -> bool {
+        self.0.char_len_eq(n)
+    }
+
+    /// Calls `func` with mutable string slice of this view window and returns its result.
+    ///
+    /// View stays usable afterwards, which makes it easy to call [`StrExt`](crate::StrExt)
+    /// methods on the window.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// view.apply(|s| s.make_uppercase());
+    /// assert_eq!(view.as_str(), "WORLD");
+    ///
+    /// let trimmed_len = view.apply(|s| s.trim_matches_char_mut('W').len());
+    /// assert_eq!(trimmed_len, 4);
+    ///
+    /// assert_eq!(text, "Hello WORLD");
+    /// ```
+    pub fn apply<R, F>(&mut self, func: F) -> R
+    where
+        F: FnOnce(&mut str) -> R,
+    {
+        func(self.as_str_mut())
+    }
+
+    /// Reduces string view from left and right while characters are whitespace.
+    ///
+    /// Same as [`trim_while(char::is_whitespace)`](Self::trim_while).
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("[\n Hello \t]");
+    /// let mut view = text.view_part_mut(1, 10);
+    ///
+    /// view.trim();
+    /// assert_eq!(view.as_str(), "Hello");
+    /// ```
+    pub fn trim(&mut self) {
+        self.0.trim_while(char::is_whitespace);
+    }
+
+    /// Reduces string view from the left while characters are whitespace.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("[\n Hello \t]");
+    /// let mut view = text.view_part_mut(1, 10);
+    ///
+    /// view.trim_start();
+    /// assert_eq!(view.as_str(), "Hello \t");
+    /// ```
+    pub fn trim_start(&mut self) {
+        self.0.reduce_left_while(char::is_whitespace);
+    }
+
+    /// Reduces string view from the right while characters are whitespace.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("[\n Hello \t]");
+    /// let mut view = text.view_part_mut(1, 10);
+    ///
+    /// view.trim_end();
+    /// assert_eq!(view.as_str(), "\n Hello");
+    /// ```
+    pub fn trim_end(&mut self) {
+        self.0.reduce_right_while(char::is_whitespace);
+    }
+
+    /// Moves this view to the right by `n` characters keeping its length in characters.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]         ]
+    /// [ str     -> [ view ]   ]
+    /// ```
+    ///
+    /// Stops at the end of base string. Returns number of characters view was moved by.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("abcdefghij");
+    /// let mut view = text.view_part_mut(0, 5);
+    ///
+    /// assert_eq!(view.rotate_window_right(3), 3);
+    /// assert_eq!(view.as_str(), "defgh");
+    ///
+    /// assert_eq!(view.rotate_window_right(3), 2);
+    /// assert_eq!(view.as_str(), "fghij");
+    /// ```
+    pub fn rotate_window_right(&mut self, n: usize) -> usize {
+        self.0.rotate_window_right(n)
+    }
+
+    /// Moves this view to the left by `n` characters keeping its length in characters.
+    ///
+    /// ```toml,ignore
+    /// [ str         [ view ]  ]
+    /// [ str  [ view ] <-      ]
+    /// ```
+    ///
+    /// Stops at the start of base string. Returns number of characters view was moved by.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("abcdefghij");
+    /// let mut view = text.view_part_mut(0, 5);
+    /// view.rotate_window_right(5);
+    ///
+    /// assert_eq!(view.rotate_window_left(3), 3);
+    /// assert_eq!(view.as_str(), "cdefg");
+    ///
+    /// assert_eq!(view.rotate_window_left(3), 2);
+    /// assert_eq!(view.as_str(), "abcde");
+    /// ```
+    pub fn rotate_window_left(&mut self, n: usize) -> usize {
+        self.0.rotate_window_left(n)
+    }
+
+    /// Returns `true` if window of this view starts with `prefix`.
+    ///
+    /// Characters of base string outside of the view are ignored.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("xHellox");
+    /// let view = text.view_part_mut(1, 6);
+    /// assert_eq!(view.as_str(), "Hello");
+    ///
+    /// assert!(view.starts_with("Hel"));
+    /// assert!(!view.starts_with("xHel"));
+    /// ```
+    pub fn starts_with(&self, prefix: &str) -> bool {
+        self.0.as_str().starts_with(prefix)
+    }
+
+    /// Returns `true` if window of this view ends with `suffix`.
+    ///
+    /// Characters of base string outside of the view are ignored.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("xHellox");
+    /// let view = text.view_part_mut(1, 6);
+    /// assert_eq!(view.as_str(), "Hello");
+    ///
+    /// assert!(view.ends_with("llo"));
+    /// assert!(!view.ends_with("llox"));
+    /// ```
+    pub fn ends_with(&self, suffix: &str) -> bool {
+        self.0.as_str().ends_with(suffix)
+    }
+
+    /// Reduce string view to the characters from `start_char` (inclusive) to `end_char`
+    /// (exclusive), where indices are counted in characters of current string view.
+    ///
+    /// ```toml,ignore
+    /// [ str [ view [ chars ] view ] str ]
+    /// [ str        [ view  ]        str ]
+    /// ```
+    ///
+    /// returns [`Err`] if `start_char > end_char` or there is not enough characters in
+    /// current string view. String view is not modified in that case.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("аб本гд");
+    ///
+    /// let mut view = text.view_mut();
+    ///
+    /// let result = view.reduce_to_char_range(1, 3);
+    /// assert!(result.is_ok());
+    /// assert_eq!(view.as_str(), "б本");
+    ///
+    /// let result = view.reduce_to_char_range(1, 3);
+    /// assert!(result.is_err());
+    /// assert_eq!(view.as_str(), "б本");
+    /// ```
+    pub fn reduce_to_char_range(
+        &mut self,
+        start_char: usize,
+        end_char: usize,
+    ) -> Result<(), ViewIsTooShort> {
+        self.0.reduce_to_char_range(start_char, end_char)
+    }
+
+    /// Writes characters from `chars` into this string view one after another starting at
+    /// the beginning of the view. Returns number of bytes written.
+    ///
+    /// Every written character has to end at a character boundary of current contents,
+    /// otherwise [`Err`] is returned. Characters written before the error stay in place.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("[..]");
+    /// let mut view = text.view_part_mut(1, 3);
+    ///
+    /// assert_eq!(view.write_chars(['H', 'i']).unwrap(), 2);
+    /// assert_eq!(view.as_str(), "Hi");
+    ///
+    /// assert!(view.write_chars("Hey".chars()).is_err());
+    /// assert_eq!(text, "[He]");
+    /// ```
+    pub fn write_chars<I>(&mut self, chars: I) -> Result<usize, CharsHaveDifferentSizes>
+    where
+        I: IntoIterator<Item = char>,
+    {
+        let window = self.as_str_mut();
+        let mut cursor = 0;
+
+        for ch in chars {
+            let ch_len = ch.len_utf8();
+
+            if !window.is_char_boundary(cursor + ch_len) {
+                return Err(CharsHaveDifferentSizes {
+                    expected: window[cursor..].chars().next().map_or(0, char::len_utf8),
+                    found: ch_len,
+                });
+            }
+
+            // Safety: both `cursor` and `cursor + ch_len` are character boundaries
+            unsafe {
+                ch.encode_utf8(&mut window.as_bytes_mut()[cursor..cursor + ch_len]);
+            }
+            cursor += ch_len;
+        }
+        Ok(cursor)
+    }
+
+    /// Returns the longest of `candidates` this string view starts with.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("<= x");
+    /// let view = text.view_mut();
+    ///
+    /// assert_eq!(view.longest_prefix(&["<", "<=", "<<"]), Some("<="));
+    /// assert_eq!(view.longest_prefix(&[">", ">="]), None);
+    /// ```
+    pub fn longest_prefix<'c>(&self, candidates: &[&'c str]) -> Option<&'c str> {
+        self.0.longest_prefix(candidates)
+    }
+
+    /// Writes characters of this string view into `buf` and returns number of characters
+    /// written. Only the first `buf.len()` characters are written if `buf` is too short.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let view = text.view_part_mut(6, 11);
+    ///
+    /// let mut buf = ['\0'; 3];
+    /// assert_eq!(view.collect_chars_into(&mut buf), 3);
+    /// assert_eq!(buf, ['W', 'o', 'r']);
+    /// ```
+    pub fn collect_chars_into(&self, buf: &mut [char]) -> usize {
+        self.0.collect_chars_into(buf)
+    }
+
+    /// Toggles case of every ASCII letter in this string view in-place.
+    /// Other characters are left untouched.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// view.swap_case_ascii_in_place();
+    /// assert_eq!(view.as_str(), "wORLD");
+    /// assert_eq!(text, "Hello wORLD");
+    /// ```
+    pub fn swap_case_ascii_in_place(&mut self) {
+        CharsInPlaceMut::new(self.as_str_mut()).for_each(|mut ch| ch.toggle_ascii_case());
+    }
+
+    /// Approximate number of terminal columns this string view takes.
+    ///
+    /// See [`StrExt::display_width`](crate::StrExt::display_width).
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let view = text.view_part_mut(6, 11);
+    ///
+    /// assert_eq!(view.display_width(), 5);
+    /// ```
+    pub fn display_width(&self) -> usize {
+        self.0.display_width()
+    }
+
+    /// Returns `i`-th byte of this string view, or [`None`] if `i` is out of range.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let view = text.view_part_mut(6, 11);
+    ///
+    /// assert_eq!(view.byte(0), Some(b'W'));
+    /// assert_eq!(view.byte(5), None);
+    /// ```
+    pub fn byte(&self, i: usize) -> Option<u8> {
+        self.0.byte(i)
+    }
+
+    /// Checks that this string view is an ASCII case-insensitive match with `other`.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("SELECT * FROM table");
+    /// let view = text.view_part_mut(0, 6);
+    ///
+    /// assert!(view.eq_ignore_ascii_case("select"));
+    /// ```
+    pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(other)
+    }
+
+    /// Checks if this string view contains `pat`, which can be a [`char`] or a string slice.
+    /// Only current window is searched, not the base string slice.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_mut();
+    ///
+    /// assert!(view.contains('W'));
+    /// assert!(view.contains("World"));
+    ///
+    /// view.reduce_right(6);
+    /// assert!(!view.contains("World"));
+    /// ```
+    pub fn contains<P: ViewPattern>(&self, pat: P) -> bool {
+        pat.occurs_in(self.as_str())
+    }
+
+    /// Replaces every ASCII byte of this string view found in `from` with the byte at the
+    /// same position in `to`, like `tr` utility does. Other characters are left untouched.
+    ///
+    /// **Panics** if `from` and `to` have different lengths or contain non-ASCII bytes.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("cabbage");
+    ///
+    /// text.view_mut().tr_ascii_in_place(b"abc", b"xyz");
+    /// assert_eq!(text, "zxyyxge");
+    /// ```
+    pub fn tr_ascii_in_place(&mut self, from: &[u8], to: &[u8]) {
+        assert_eq!(
+            from.len(),
+            to.len(),
+            "Byte sets for translation have to have the same length"
+        );
+        assert!(
+            from.is_ascii() && to.is_ascii(),
+            "Byte sets for translation have to be ASCII"
+        );
+
+        let mut table: [u8; 128] = core::array::from_fn(|b| b as u8);
+        for (&from, &to) in from.iter().zip(to) {
+            table[from as usize] = to;
+        }
+
+        // SAFETY: ASCII bytes are replaced with ASCII bytes, other bytes are not modified
+        let bytes = unsafe { self.as_str_mut().as_bytes_mut() };
+        for byte in bytes.iter_mut().filter(|byte| byte.is_ascii()) {
+            *byte = table[*byte as usize];
+        }
+    }
+
+    /// Returns number of lines in this string view. Lines are counted the same way as
+    /// in [`str::lines`], so trailing line terminator does not start a new line.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("one\ntwo\nthree\n");
+    /// let view = text.view_part_mut(4, 14);
+    ///
+    /// assert_eq!(view.as_str(), "two\nthree\n");
+    /// assert_eq!(view.line_count(), 2);
+    /// ```
+    pub fn line_count(&self) -> usize {
+        self.0.line_count()
+    }
+
+    /// Returns bytes of this string view.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let view = text.view_part_mut(6, 11);
+    ///
+    /// assert_eq!(view.as_bytes(), b"World");
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    /// Returns mutable bytes of this string view.
+    ///
+    /// # Safety
+    ///
+    /// Same as [`str::as_bytes_mut`]: the caller must ensure that the content of the slice
+    /// is valid `UTF-8` before the borrow ends and the underlying string slice is used.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// unsafe { view.as_bytes_mut()[0] = b'w' };
+    /// assert_eq!(text, "Hello world");
+    /// ```
+    pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
+        unsafe { self.as_str_mut().as_bytes_mut() }
+    }
+
+    /// Extend string view to the right up to and including the first `delim` character.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]          delim ]
+    /// [ str  [  view  ->  delim ]    ]
+    /// ```
+    ///
+    /// returns `false` if `delim` was not found, in which case view is extended to the end
+    /// of base string slice.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("key=value\nnext");
+    /// let mut view = text.view_part_mut(0, 0);
+    ///
+    /// assert!(view.extend_right_to('\n'));
+    /// assert_eq!(view.as_str(), "key=value\n");
+    ///
+    /// assert!(!view.extend_right_to('\n'));
+    /// assert_eq!(view.as_str(), "key=value\nnext");
+    /// ```
+    pub fn extend_right_to(&mut self, delim: char) -> bool {
+        self.0.extend_right_to(delim)
+    }
+
+    /// Extend string view to the left up to and including the first `delim` character.
+    ///
+    /// ```toml,ignore
+    /// [ str delim          [ view ]  ]
+    /// [ str [ delim  <-  view     ]  ]
+    /// ```
+    ///
+    /// returns `false` if `delim` was not found, in which case view is extended to the start
+    /// of base string slice.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("key=value\nnext");
+    /// let mut view = text.view_part_mut(9, 9);
+    ///
+    /// assert!(view.extend_left_to('='));
+    /// assert_eq!(view.as_str(), "=value");
+    ///
+    /// assert!(!view.extend_left_to('='));
+    /// assert_eq!(view.as_str(), "key=value");
+    /// ```
+    pub fn extend_left_to(&mut self, delim: char) -> bool {
+        self.0.extend_left_to(delim)
+    }
+
+    /// Extend string view to the right while `func` returns `true`. First argument of `func`
+    /// is byte offset of the character relative to current view start.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]         ]
+    /// [ str  [  view  -> n ]  ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(0, 1);
+    ///
+    /// // Extend while alphabetic, but at most 4 bytes long
+    /// view.extend_right_while_indexed(|idx, ch| idx < 4 && ch.is_alphabetic());
+    /// assert_eq!(view.as_str(), "Hell");
+    /// ```
+    pub fn extend_right_while_indexed<F>(&mut self, func: F)
+    where
+        F: FnMut(usize, char) -> bool,
+    {
+        self.0.extend_right_while_indexed(func);
+    }
+
+    /// Reduce string view from the right while `func` returns `true`. First argument of `func`
+    /// is byte offset of the character relative to current view start.
+    ///
+    /// ```toml,ignore
+    /// [ str  [   view   ]  ]
+    /// [ str  [ view ] <- n ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(0, 11);
+    ///
+    /// // Keep at least 7 bytes
+    /// view.reduce_right_while_indexed(|idx, ch| idx >= 7 && ch != ' ');
+    /// assert_eq!(view.as_str(), "Hello W");
+    /// ```
+    pub fn reduce_right_while_indexed<F>(&mut self, func: F)
+    where
+        F: FnMut(usize, char) -> bool,
+    {
+        self.0.reduce_right_while_indexed(func);
+    }
+
+    /// Reduce string view from the left while `func` returns `true`. First argument of `func`
+    /// is byte offset of the character relative to current view start.
+    ///
+    /// ```toml,ignore
+    /// [ str   [   view   ]   ]
+    /// [ str  n -> [ view ]   ]
+    /// ```
+    ///
+    /// #### Example:
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(0, 11);
+    ///
+    /// // Skip at most 3 bytes
+    /// view.reduce_left_while_indexed(|idx, ch| idx < 3 && ch != ' ');
+    /// assert_eq!(view.as_str(), "lo World");
+    /// ```
+    pub fn reduce_left_while_indexed<F>(&mut self, func: F)
+    where
+        F: FnMut(usize, char) -> bool,
+    {
+        self.0.reduce_left_while_indexed(func);
+    }
+
+    /// Extend string view to the right by the smallest number of characters needed for it
+    /// to contain `needle`.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]            ]
+    /// [ str  [  view  needle ]   ]
+    /// ```
+    ///
+    /// returns [`Err`] if base string slice doesn't contain `needle` to the right of view
+    /// start. String view is not modified in that case.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("<a>text</a>");
+    /// let mut view = text.view_part_mut(0, 0);
+    ///
+    /// assert!(view.extend_right_until_contains("</a>").is_ok());
+    /// assert_eq!(view.as_str(), "<a>text</a>");
+    ///
+    /// assert!(view.extend_right_until_contains("</b>").is_err());
+    /// ```
+    pub fn extend_right_until_contains(
+        &mut self,
+        needle: &str,
+    ) -> Result<(), BaseStringIsTooShort> {
+        self.0.extend_right_until_contains(needle)
+    }
+
+    /// Extend string view to the right by `n` characters or up to the end of base string
+    /// slice if there are less. Returns number of characters view was extended by.
+    ///
+    /// ```toml,ignore
+    /// [ str  [ view ]         ]
+    /// [ str  [  view  -> n ]  ]
+    /// ```
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(0, 5);
+    ///
+    /// assert_eq!(view.extend_right_saturating(10), 6);
+    /// assert_eq!(view.as_str(), "Hello World");
+    /// ```
+    pub fn extend_right_saturating(&mut self, n: usize) -> usize {
+        self.0.extend_right_saturating(n)
+    }
+
+    /// Extend string view to the left by `n` characters or up to the start of base string
+    /// slice if there are less. Returns number of characters view was extended by.
+    ///
+    /// ```toml,ignore
+    /// [ str         [ view ]  ]
+    /// [ str  [ n <- view   ]  ]
+    /// ```
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// assert_eq!(view.extend_left_saturating(10), 6);
+    /// assert_eq!(view.as_str(), "Hello World");
+    /// ```
+    pub fn extend_left_saturating(&mut self, n: usize) -> usize {
+        self.0.extend_left_saturating(n)
+    }
+
+    /// Reduce string view from the right by `n` characters or to length zero if there are
+    /// less. Returns number of characters view was reduced by.
+    ///
+    /// ```toml,ignore
+    /// [ str  [   view   ]  ]
+    /// [ str  [ view ] <- n ]
     /// ```
-    pub fn try_reduce_left(&mut self, n: usize) -> Result<(), ViewIsTooShort<LEFT>> {
-        self.0.try_reduce_left(n)
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(0, 5);
+    ///
+    /// assert_eq!(view.reduce_right_saturating(10), 5);
+    /// assert_eq!(view.as_str(), "");
+    /// ```
+    pub fn reduce_right_saturating(&mut self, n: usize) -> usize {
+        self.0.reduce_right_saturating(n)
+    }
+
+    /// Reduce string view from the left by `n` characters or to length zero if there are
+    /// less. Returns number of characters view was reduced by.
+    ///
+    /// ```toml,ignore
+    /// [ str   [   view   ]   ]
+    /// [ str  n -> [ view ]   ]
+    /// ```
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// assert_eq!(view.reduce_left_saturating(10), 5);
+    /// assert_eq!((view.as_str(), view.start()), ("", 11));
+    /// ```
+    pub fn reduce_left_saturating(&mut self, n: usize) -> usize {
+        self.0.reduce_left_saturating(n)
+    }
+
+    /// Returns index of the character of this string view displayed at terminal `column`,
+    /// or [`None`] if view is shorter.
+    ///
+    /// Every character is assumed to take a single column. With `width` feature enabled
+    /// width of every character is measured using `unicode-width`, see
+    /// [`StrExt::display_width`](crate::StrExt::display_width).
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("hello");
+    /// let view = text.view_mut();
+    ///
+    /// assert_eq!(view.char_idx_at_column(3), Some(3));
+    /// assert_eq!(view.char_idx_at_column(5), None);
+    /// ```
+    pub fn char_idx_at_column(&self, column: usize) -> Option<usize> {
+        self.0.char_idx_at_column(column)
+    }
+
+    /// Replaces every byte of this string view with copies of `ch`.
+    ///
+    /// **Panics** if length of this view is not a multiple of `ch` length in bytes.
+    ///
+    /// see [`StringViewMut::try_replace_with_char`] for fallible version.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from(">>Мир<<");
+    /// let mut view = text.view_part_mut(2, 8);
+    ///
+    /// view.replace_with_char('ж');
+    /// assert_eq!(text, ">>жжж<<");
+    /// ```
+    pub fn replace_with_char(&mut self, ch: char) {
+        self.try_replace_with_char(ch)
+            .expect("Unable to replace string view with character")
+    }
+
+    /// Try to replace every byte of this string view with copies of `ch`.
+    ///
+    /// returns [`Err`] if length of this view is not a multiple of `ch` length in bytes.
+    /// String view is not modified in that case.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from(">>World<<");
+    /// let mut view = text.view_part_mut(2, 7);
+    ///
+    /// assert!(view.try_replace_with_char('ж').is_err());
+    /// assert!(view.try_replace_with_char('*').is_ok());
+    /// assert_eq!(text, ">>*****<<");
+    /// ```
+    pub fn try_replace_with_char(&mut self, ch: char) -> Result<(), CharDoesNotFit> {
+        let view_len = self.as_str().len();
+
+        if !view_len.is_multiple_of(ch.len_utf8()) {
+            return Err(CharDoesNotFit {
+                view_len,
+                char_len: ch.len_utf8(),
+            });
+        }
+        self.as_str_mut().replace_with_char(ch);
+        Ok(())
+    }
+
+    /// Fills this string view with copies of `ch`. Same as [`StringViewMut::replace_with_char`].
+    ///
+    /// **Panics** if length of this view is not a multiple of `ch` length in bytes.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("password: hunter2");
+    /// let mut view = text.view_part_mut(10, 17);
+    ///
+    /// view.fill('*');
+    /// assert_eq!(text, "password: *******");
+    /// ```
+    pub fn fill(&mut self, ch: char) {
+        self.replace_with_char(ch);
+    }
+
+    /// Calls `func` with mutable string slice of every word of this string view.
+    /// Words are separated by whitespace which is left untouched.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("[hello  big\tworld]");
+    /// let mut view = text.view_part_mut(1, 18);
+    ///
+    /// view.for_each_word_mut(|word| word.make_uppercase());
+    /// assert_eq!(text, "[HELLO  BIG\tWORLD]");
+    /// ```
+    pub fn for_each_word_mut<F: FnMut(&mut str)>(&mut self, mut func: F) {
+        let mut rest: &mut str = self.as_str_mut();
+
+        while let Some(start) = rest.find(|ch: char| !ch.is_whitespace()) {
+            let end = rest[start..]
+                .find(char::is_whitespace)
+                .map_or(rest.len(), |len| start + len);
+
+            let (word, next) = core::mem::take(&mut rest).split_at_mut(end);
+            func(&mut word[start..]);
+            rest = next;
+        }
+    }
+
+    /// Number of bytes debug-escaped form of this string view takes,
+    /// i.e. sum of [`char::escape_debug`] lengths of its characters in bytes.
+    ///
+    /// Useful to allocate buffer of exact size before escaping.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("[a\tb]");
+    /// let view = text.view_part_mut(1, 4);
+    ///
+    /// assert_eq!(view.escape_debug_len(), 4); // a\tb
+    /// ```
+    pub fn escape_debug_len(&self) -> usize {
+        self.0.escape_debug_len()
+    }
+
+    /// Replaces whole string view with another string slice with same length in-place.
+    /// Parts of base string slice outside of this view are not modified.
+    ///
+    /// **Panics** if replacement string slice has different length.
+    ///
+    /// see [`StringViewMut::try_replace_in_place`] for fallible version.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// view.replace_in_place("WORLD");
+    /// assert_eq!(text, "Hello WORLD");
+    /// ```
+    pub fn replace_in_place(&mut self, rep: &str) {
+        self.as_str_mut().replace_in_place(rep);
+    }
+
+    /// Replaces whole string view with another string slice with same length in-place.
+    ///
+    /// returns [`Err`] if replacement string slice has different length.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// let err = view.try_replace_in_place("Rust").unwrap_err();
+    /// assert_eq!((err.expected, err.found), (5, 4));
+    ///
+    /// assert!(view.try_replace_in_place("WORLD").is_ok());
+    /// assert_eq!(text, "Hello WORLD");
+    /// ```
+    pub fn try_replace_in_place(&mut self, rep: &str) -> Result<(), ReplaceError> {
+        self.as_str_mut().try_replace_in_place(rep)
+    }
+
+    /// Makes characters of this string view uppercase in-place where appropriate.
+    ///
+    /// Doesn't change character if uppercase variant takes different amount of bytes.
+    ///
+    /// See [`StrExt::make_uppercase`](crate::StrExt::make_uppercase).
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("привет мир");
+    /// let mut view = text.view_part_mut(13, 19);
+    ///
+    /// view.make_uppercase();
+    /// assert_eq!(text, "привет МИР");
+    /// ```
+    pub fn make_uppercase(&mut self) {
+        self.as_str_mut().make_uppercase();
+    }
+
+    /// Makes characters of this string view lowercase in-place where appropriate.
+    ///
+    /// Doesn't change character if lowercase variant takes different amount of bytes.
+    ///
+    /// See [`StrExt::make_lowercase`](crate::StrExt::make_lowercase).
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("ПРИВЕТ МИР");
+    /// let mut view = text.view_part_mut(0, 12);
+    ///
+    /// view.make_lowercase();
+    /// assert_eq!(text, "привет МИР");
+    /// ```
+    pub fn make_lowercase(&mut self) {
+        self.as_str_mut().make_lowercase();
+    }
+
+    /// Makes ASCII characters of this string view uppercase in-place.
+    /// Other characters are left untouched.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("hello world");
+    /// let mut view = text.view_part_mut(6, 11);
+    ///
+    /// view.make_ascii_uppercase();
+    /// assert_eq!(text, "hello WORLD");
+    /// ```
+    pub fn make_ascii_uppercase(&mut self) {
+        self.as_str_mut().make_ascii_uppercase();
+    }
+
+    /// Makes ASCII characters of this string view lowercase in-place.
+    /// Other characters are left untouched.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("HELLO WORLD");
+    /// let mut view = text.view_part_mut(0, 5);
+    ///
+    /// view.make_ascii_lowercase();
+    /// assert_eq!(text, "hello WORLD");
+    /// ```
+    pub fn make_ascii_lowercase(&mut self) {
+        self.as_str_mut().make_ascii_lowercase();
+    }
+
+    /// Returns shorter-lived [`StringViewMut`] over the same base string slice and window.
+    ///
+    /// Reborrowed view can be navigated and modified independently, while this view
+    /// keeps its window once reborrow is dropped.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("Hello World");
+    /// let mut view = text.view_part_mut(0, 5);
+    ///
+    /// let mut reborrow = view.reborrow();
+    /// reborrow.extend_right(6);
+    /// reborrow.reduce_left(6);
+    /// reborrow.make_ascii_uppercase();
+    ///
+    /// assert_eq!(view.as_str(), "Hello");
+    /// assert_eq!(text, "Hello WORLD");
+    /// ```
+    pub fn reborrow(&mut self) -> StringViewMut<'_> {
+        StringViewMut(View {
+            base: &mut *self.0.base,
+            view_start: self.0.view_start,
+            view_len: self.0.view_len,
+        })
+    }
+
+    /// Checks that characters of this string view match characters of `other` pairwise
+    /// using `cmp`. Returns `false` if they have different number of characters.
+    ///
+    /// ```rust
+    /// use string_view::StrExt;
+    ///
+    /// let mut text = String::from("id: a1b");
+    /// let view = text.view_part_mut(4, 7);
+    ///
+    /// let same_kind = |a: char, b: char| a == b || a.is_ascii_digit() && b.is_ascii_digit();
+    ///
+    /// assert!(view.chars_eq_by("a9b", same_kind));
+    /// assert!(!view.chars_eq_by("a9", same_kind));
+    /// ```
+    pub fn chars_eq_by<F: FnMut(char, char) -> bool>(&self, other: &str, cmp: F) -> bool {
+        self.0.chars_eq_by(other, cmp)
+    }
+}
+
+impl Debug for StringViewMut<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for StringViewMut<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for StringViewMut<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for StringViewMut<'_> {}
+
+impl PartialOrd for StringViewMut<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for StringViewMut<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for StringViewMut<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl AsRef<str> for StringViewMut<'_> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Borrow<str> for StringViewMut<'_> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+/// Serializes contents of string view as a plain string. Requires `serde` feature.
+///
+/// See [`StringView`] for why `Deserialize` is not implemented.
+#[cfg(feature = "serde")]
+impl serde::Serialize for StringViewMut<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// Same as [`StringViewMut::new`].
+///
+/// ```rust
+/// use string_view::StringViewMut;
+///
+/// let mut text = String::from("Hello");
+/// let view = StringViewMut::from(&mut text[..]);
+/// assert_eq!(view.as_str(), "Hello");
+/// ```
+impl<'a> From<&'a mut str> for StringViewMut<'a> {
+    fn from(base: &'a mut str) -> Self {
+        StringViewMut::new(base)
+    }
+}
+
+/// Iterates over characters of string view in-place.
+///
+/// ```rust
+/// use string_view::StrExt;
+///
+/// let mut text = String::from("Hello World");
+/// let mut view = text.view_part_mut(6, 11);
+///
+/// for mut ch in &mut view {
+///     ch.make_uppercase().unwrap();
+/// }
+/// assert_eq!(text, "Hello WORLD");
+/// ```
+impl<'b> IntoIterator for &'b mut StringViewMut<'_> {
+    type Item = CharMut<'b>;
+    type IntoIter = CharsInPlaceMut<'b>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        CharsInPlaceMut::new(self.as_str_mut())
+    }
+}
+
+/// Pattern which can be searched for in string view.
+///
+/// Implemented for [`char`] and string slices. See [`StringView::contains`].
+pub trait ViewPattern {
+    fn occurs_in(self, haystack: &str) -> bool;
+}
+
+impl ViewPattern for char {
+    fn occurs_in(self, haystack: &str) -> bool {
+        haystack.contains(self)
+    }
+}
+
+impl ViewPattern for &str {
+    fn occurs_in(self, haystack: &str) -> bool {
+        haystack.contains(self)
+    }
+}
+
+/// Fixed-capacity stack of [`StringView`] positions. Useful for backtracking parsers.
+///
+/// Stores up to `N` `(start, len)` byte offset snapshots without allocation.
+///
+/// ```rust
+/// use string_view::{StrExt, ViewStack};
+///
+/// let text = "let x = 5;";
+/// let mut view = text.view_part(0, 0);
+/// let mut stack = ViewStack::<4>::new();
+///
+/// view.extend_right_while(char::is_alphabetic);
+/// assert_eq!(view.as_str(), "let");
+/// stack.push(&view);
+///
+/// view.extend_right_while(|ch| ch != ';');
+/// assert_eq!(view.as_str(), "let x = 5");
+///
+/// assert!(stack.restore(&mut view));
+/// assert_eq!(view.as_str(), "let");
+/// ```
+pub struct ViewStack<const N: usize> {
+    snapshots: [(usize, usize); N],
+    len: usize,
+}
+
+impl<const N: usize> ViewStack<N> {
+    /// Creates empty stack.
+    pub const fn new() -> Self {
+        ViewStack {
+            snapshots: [(0, 0); N],
+            len: 0,
+        }
+    }
+
+    /// Number of snapshots in this stack.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Saves position of `view` on top of this stack.
+    ///
+    /// panics if stack already contains `N` snapshots.
+    ///
+    /// see [`ViewStack::try_push`] for fallible version.
+    pub fn push(&mut self, view: &StringView) {
+        self.try_push(view)
+            .expect("Unable to push view position to the stack")
     }
 
-    /// Reduce string view from the left while `func` returns `true`.
-    ///
-    /// ```toml,ignore
-    /// [ str   [   view   ]   ]
-    /// [ str  n -> [ view ]   ]
-    /// ```
+    /// Saves position of `view` on top of this stack.
     ///
-    /// #### Example:
+    /// returns [`Err`] if stack already contains `N` snapshots.
     ///
     /// ```rust
-    /// use string_view::StrExt;
-    ///
-    /// let mut text = String::from("Hello World !!!");
-    ///
-    /// let mut view = text.view_mut();
-    /// assert_eq!(view.as_str(), "Hello World !!!");
+    /// use string_view::{StrExt, ViewStack};
     ///
-    /// view.reduce_left_while(|ch| ch != ' ');
-    /// assert_eq!(view.as_str(), " World !!!");
+    /// let view = "Hello".view();
+    /// let mut stack = ViewStack::<1>::new();
     ///
-    /// view.reduce_left(1);
-    /// view.reduce_left_while(|ch| ch != ' ');
-    /// assert_eq!(view.as_str(), " !!!");
+    /// assert!(stack.try_push(&view).is_ok());
+    /// assert!(matches!(stack.try_push(&view), Err(ViewStackIsFull)));
     /// ```
-    pub fn reduce_left_while<F>(&mut self, func: F)
-    where
-        F: FnMut(char) -> bool,
-    {
-        self.0.reduce_left_while(func);
+    pub fn try_push(&mut self, view: &StringView) -> Result<(), ViewStackIsFull> {
+        let slot = self.snapshots.get_mut(self.len).ok_or(ViewStackIsFull)?;
+        *slot = (view.0.view_start, view.0.view_len);
+        self.len += 1;
+        Ok(())
     }
 
-    /// Reduces string view from left and right while `func` returns `true`
-    ///
-    /// ```
-    /// use string_view::StrExt;
-    ///
-    /// let mut text = String::from("  \n   Hello  \n \t  ");
-    /// let mut view = text.view_mut();
+    /// Removes last snapshot from this stack and returns it as `(start, len)` byte offsets.
+    pub fn pop(&mut self) -> Option<(usize, usize)> {
+        self.len = self.len.checked_sub(1)?;
+        Some(self.snapshots[self.len])
+    }
+
+    /// Removes last snapshot from this stack and moves `view` to its position.
     ///
-    /// view.trim_while(char::is_whitespace);
+    /// Returns `false` and leaves `view` unchanged if this stack is empty.
     ///
-    /// assert_eq!(view.as_str(), "Hello");
-    /// ```
-    pub fn trim_while<F: FnMut(char) -> bool>(&mut self, func: F) {
-        self.0.trim_while(func);
+    /// **Panics** if snapshot doesn't fit into base string of `view` or is not on char
+    /// boundaries, which can happen if snapshot was taken from a view of another string.
+    pub fn restore(&mut self, view: &mut StringView) -> bool {
+        let Some((view_start, view_len)) = self.pop()
+        else {
+            return false;
+        };
+        assert!(
+            view.0.base.is_char_boundary(view_start)
+                && view.0.base.is_char_boundary(view_start + view_len),
+            "Snapshot has to be inside base string and on char boundaries"
+        );
+        view.0.view_start = view_start;
+        view.0.view_len = view_len;
+        true
     }
 }
 
-impl Debug for StringViewMut<'_> {
+impl<const N: usize> Default for ViewStack<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The only error case in [`ViewStack::try_push`].
+pub struct ViewStackIsFull;
+
+impl Debug for ViewStackIsFull {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        Debug::fmt(self.as_str(), f)
+        write!(f, "View stack has no space left for another snapshot")
     }
 }
 
-impl Display for StringViewMut<'_> {
+impl Display for ViewStackIsFull {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        Display::fmt(self.as_str(), f)
+        Debug::fmt(&self, f)
+    }
+}
+
+impl Error for ViewStackIsFull {}
+
+/// Byte range is out of bounds of string view or does not lie on character boundaries.
+///
+/// The only error case in [`StringView::try_sub_view`].
+pub struct InvalidRange {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Debug for InvalidRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "Byte range {}..{} is out of bounds of string view or does not lie on character boundaries",
+            self.start, self.end
+        )
+    }
+}
+
+impl Display for InvalidRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self, f)
+    }
+}
+
+impl Error for InvalidRange {}
+
+/// Side of string view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Display for Side {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Side::Left => write!(f, "left"),
+            Side::Right => write!(f, "right"),
+        }
+    }
+}
+
+/// Length of string view is not a multiple of character length in bytes.
+///
+/// The only error case in [`StringViewMut::try_replace_with_char`].
+pub struct CharDoesNotFit {
+    pub view_len: usize,
+    pub char_len: usize,
+}
+
+impl Debug for CharDoesNotFit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "String view of {} bytes cannot be filled with character of {} bytes. View length has to be a multiple of character length",
+            self.view_len, self.char_len
+        )
+    }
+}
+
+impl Display for CharDoesNotFit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self, f)
     }
 }
 
-type Side = bool;
-const RIGHT: bool = true;
-const LEFT: bool = false;
+impl Error for CharDoesNotFit {}
+
+/// Error case of [`StringView::try_extend_right`] and [`StringView::try_extend_left`].
+pub struct BaseStringIsTooShort {
+    side: Side,
+}
 
-/// The only error case in [`StringView::try_extend_right`].
-pub struct BaseStringIsTooShort<const SIDE: Side>;
+impl BaseStringIsTooShort {
+    /// Side of the view where base string has not enough characters.
+    pub fn side(&self) -> Side {
+        self.side
+    }
+}
 
-impl<const SIDE: Side> Debug for BaseStringIsTooShort<SIDE> {
+impl Debug for BaseStringIsTooShort {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "Base String contains less characters than `n` to the {} of the view",
-            if SIDE == RIGHT { "right" } else { "left" }
+            self.side
         )
     }
 }
 
-impl<const SIDE: Side> Display for BaseStringIsTooShort<SIDE> {
+impl Display for BaseStringIsTooShort {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         Debug::fmt(&self, f)
     }
 }
 
-impl<const SIDE: Side> Error for BaseStringIsTooShort<SIDE> {}
+impl Error for BaseStringIsTooShort {}
+
+/// Error case of [`StringView::try_reduce_right`] and [`StringView::try_reduce_left`].
+pub struct ViewIsTooShort {
+    side: Side,
+}
 
-/// The only error case in [`StringView::try_reduce_right`].
-pub struct ViewIsTooShort<const SIDE: Side>;
+impl ViewIsTooShort {
+    /// Side of the view which has not enough characters.
+    pub fn side(&self) -> Side {
+        self.side
+    }
+}
 
-impl<const SIDE: Side> Debug for ViewIsTooShort<SIDE> {
+impl Debug for ViewIsTooShort {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(
             f,
             "View contains less characters than `n` to the {} of the view",
-            if SIDE == RIGHT { "right" } else { "left" }
+            self.side
         )
     }
 }
 
-impl<const SIDE: Side> Display for ViewIsTooShort<SIDE> {
+impl Display for ViewIsTooShort {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self, f)
+    }
+}
+
+impl Error for ViewIsTooShort {}
+
+/// Error of any fallible operation which extends or reduces string view.
+///
+/// Errors of `try_` methods can be converted into it, which allows to use `?` with
+/// all of them in a single function.
+///
+/// ```rust
+/// use string_view::{StrExt, StringView, ViewError, Side};
+///
+/// fn shift_right(view: &mut StringView, n: usize) -> Result<(), ViewError> {
+///     view.try_extend_right(n)?;
+///     view.try_reduce_left(n)?;
+///     Ok(())
+/// }
+///
+/// let text = "Hello World";
+/// let mut view = text.view_part(0, 5);
+///
+/// assert!(shift_right(&mut view, 6).is_ok());
+/// assert_eq!(view.as_str(), "World");
+///
+/// let err = shift_right(&mut view, 1).unwrap_err();
+/// assert!(matches!(err, ViewError::BaseTooShort { side: Side::Right }));
+/// ```
+pub enum ViewError {
+    BaseTooShort { side: Side },
+    ViewTooShort { side: Side },
+}
+
+impl Debug for ViewError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ViewError::BaseTooShort { side } => write!(
+                f,
+                "Base String contains less characters than `n` to the {side} of the view"
+            ),
+            ViewError::ViewTooShort { side } => write!(
+                f,
+                "View contains less characters than `n` to the {side} of the view"
+            ),
+        }
+    }
+}
+
+impl Display for ViewError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         Debug::fmt(&self, f)
     }
 }
 
-impl<const SIDE: Side> Error for ViewIsTooShort<SIDE> {}
+impl Error for ViewError {}
+
+impl From<BaseStringIsTooShort> for ViewError {
+    fn from(err: BaseStringIsTooShort) -> Self {
+        ViewError::BaseTooShort { side: err.side }
+    }
+}
+
+impl From<ViewIsTooShort> for ViewError {
+    fn from(err: ViewIsTooShort) -> Self {
+        ViewError::ViewTooShort { side: err.side }
+    }
+}
 
 struct View<T: AsRef<str>> {
     base: T,
@@ -1063,6 +3858,10 @@ struct View<T: AsRef<str>> {
 }
 
 impl<T: AsRef<str>> View<T> {
+    pub fn as_str(&self) -> &str {
+        &self.base.as_ref()[self.start()..self.end()]
+    }
+
     pub fn start(&self) -> usize {
         self.view_start
     }
@@ -1084,11 +3883,14 @@ impl<T: AsRef<str>> View<T> {
             .expect("Unable to extend string view to the right")
     }
 
-    pub fn try_extend_right(&mut self, n: usize) -> Result<(), BaseStringIsTooShort<RIGHT>> {
+    pub fn try_extend_right(&mut self, n: usize) -> Result<(), BaseStringIsTooShort> {
         let mut combined_len = 0;
         let mut char_iter = self.base.as_ref()[self.end()..].chars();
         for _ in 0..n {
-            combined_len += char_iter.next().ok_or(BaseStringIsTooShort)?.len_utf8();
+            combined_len += char_iter
+                .next()
+                .ok_or(BaseStringIsTooShort { side: Side::Right })?
+                .len_utf8();
         }
         self.view_len += combined_len;
         Ok(())
@@ -1116,11 +3918,14 @@ impl<T: AsRef<str>> View<T> {
             .expect("Unable to reduce string view from the right")
     }
 
-    pub fn try_reduce_right(&mut self, n: usize) -> Result<(), ViewIsTooShort<RIGHT>> {
+    pub fn try_reduce_right(&mut self, n: usize) -> Result<(), ViewIsTooShort> {
         let mut combined_len = 0;
         let mut char_iter = self.base.as_ref()[self.start()..self.end()].chars().rev();
         for _ in 0..n {
-            combined_len += char_iter.next().ok_or(ViewIsTooShort)?.len_utf8();
+            combined_len += char_iter
+                .next()
+                .ok_or(ViewIsTooShort { side: Side::Right })?
+                .len_utf8();
         }
         self.view_len -= combined_len;
         Ok(())
@@ -1147,11 +3952,14 @@ impl<T: AsRef<str>> View<T> {
             .expect("Unable to extend string view to the left")
     }
 
-    pub fn try_extend_left(&mut self, n: usize) -> Result<(), BaseStringIsTooShort<LEFT>> {
+    pub fn try_extend_left(&mut self, n: usize) -> Result<(), BaseStringIsTooShort> {
         let mut combined_len = 0;
         let mut char_iter = self.base.as_ref()[..self.start()].chars().rev();
         for _ in 0..n {
-            combined_len += char_iter.next().ok_or(BaseStringIsTooShort)?.len_utf8();
+            combined_len += char_iter
+                .next()
+                .ok_or(BaseStringIsTooShort { side: Side::Left })?
+                .len_utf8();
         }
         self.view_start -= combined_len;
         self.view_len += combined_len;
@@ -1181,11 +3989,14 @@ impl<T: AsRef<str>> View<T> {
             .expect("Unable to reduce string view from the left")
     }
 
-    pub fn try_reduce_left(&mut self, n: usize) -> Result<(), ViewIsTooShort<LEFT>> {
+    pub fn try_reduce_left(&mut self, n: usize) -> Result<(), ViewIsTooShort> {
         let mut combined_len = 0;
         let mut char_iter = self.base.as_ref()[self.start()..self.end()].chars();
         for _ in 0..n {
-            combined_len += char_iter.next().ok_or(ViewIsTooShort)?.len_utf8();
+            combined_len += char_iter
+                .next()
+                .ok_or(ViewIsTooShort { side: Side::Left })?
+                .len_utf8();
         }
         self.view_start += combined_len;
         self.view_len -= combined_len;
@@ -1213,4 +4024,293 @@ impl<T: AsRef<str>> View<T> {
         self.reduce_left_while(&mut func);
         self.reduce_right_while(&mut func);
     }
+
+    pub fn fold_chars<B, F>(&self, init: B, func: F) -> B
+    where
+        F: FnMut(B, char) -> B,
+    {
+        self.base.as_ref()[self.start()..self.end()]
+            .chars()
+            .fold(init, func)
+    }
+
+    pub fn extend_both_while<F: FnMut(char) -> bool>(&mut self, mut func: F) {
+        self.extend_left_while(&mut func);
+        self.extend_right_while(&mut func);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.view_len == 0
+    }
+
+    pub fn is_blank(&self) -> bool {
+        self.base.as_ref()[self.start()..self.end()]
+            .chars()
+            .all(char::is_whitespace)
+    }
+
+    pub fn char_len_at_least(&self, n: usize) -> bool {
+        // Every character takes from 1 to 4 bytes
+        if self.view_len < n {
+            return false;
+        }
+        if self.view_len >= n.saturating_mul(4) {
+            return true;
+        }
+        self.base.as_ref()[self.start()..self.end()]
+            .chars()
+            .nth(n - 1)
+            .is_some()
+    }
+
+    pub fn char_len_eq(&self, n: usize) -> bool {
+        // Every character takes from 1 to 4 bytes
+        if self.view_len < n || self.view_len > n.saturating_mul(4) {
+            return false;
+        }
+        let mut chars = self.base.as_ref()[self.start()..self.end()].chars();
+        chars.by_ref().take(n).count() == n && chars.next().is_none()
+    }
+
+    pub fn rotate_window_right(&mut self, n: usize) -> usize {
+        let mut moved = 0;
+        let mut combined_len = 0;
+        for ch in self.base.as_ref()[self.end()..].chars().take(n) {
+            moved += 1;
+            combined_len += ch.len_utf8();
+        }
+        self.view_len += combined_len;
+        self.reduce_left(moved);
+        moved
+    }
+
+    pub fn rotate_window_left(&mut self, n: usize) -> usize {
+        let mut moved = 0;
+        let mut combined_len = 0;
+        for ch in self.base.as_ref()[..self.start()].chars().rev().take(n) {
+            moved += 1;
+            combined_len += ch.len_utf8();
+        }
+        self.view_start -= combined_len;
+        self.view_len += combined_len;
+        self.reduce_right(moved);
+        moved
+    }
+
+    pub fn reduce_to_char_range(
+        &mut self,
+        start_char: usize,
+        end_char: usize,
+    ) -> Result<(), ViewIsTooShort> {
+        if start_char > end_char {
+            return Err(ViewIsTooShort { side: Side::Left });
+        }
+        let mut boundaries = self
+            .as_str()
+            .char_indices()
+            .map(|(idx, _)| idx)
+            .chain(core::iter::once(self.view_len));
+
+        let start = boundaries
+            .nth(start_char)
+            .ok_or(ViewIsTooShort { side: Side::Left })?;
+        let end = match end_char - start_char {
+            0 => start,
+            n => boundaries
+                .nth(n - 1)
+                .ok_or(ViewIsTooShort { side: Side::Left })?,
+        };
+        self.view_start += start;
+        self.view_len = end - start;
+        Ok(())
+    }
+
+    pub fn longest_prefix<'c>(&self, candidates: &[&'c str]) -> Option<&'c str> {
+        let window = self.as_str();
+        candidates
+            .iter()
+            .copied()
+            .filter(|candidate| window.starts_with(candidate))
+            .max_by_key(|candidate| candidate.len())
+    }
+
+    pub fn collect_chars_into(&self, buf: &mut [char]) -> usize {
+        buf.iter_mut()
+            .zip(self.as_str().chars())
+            .map(|(slot, ch)| *slot = ch)
+            .count()
+    }
+
+    pub fn display_width(&self) -> usize {
+        self.as_str().display_width()
+    }
+
+    pub fn byte(&self, i: usize) -> Option<u8> {
+        self.as_str().as_bytes().get(i).copied()
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.as_str().lines().count()
+    }
+
+    pub fn extend_right_to(&mut self, delim: char) -> bool {
+        self.extend_right_while(|ch| ch != delim);
+
+        let found = self.base.as_ref()[self.end()..].starts_with(delim);
+        if found {
+            self.view_len += delim.len_utf8();
+        }
+        found
+    }
+
+    pub fn extend_left_to(&mut self, delim: char) -> bool {
+        self.extend_left_while(|ch| ch != delim);
+
+        let found = self.base.as_ref()[..self.start()].ends_with(delim);
+        if found {
+            self.view_start -= delim.len_utf8();
+            self.view_len += delim.len_utf8();
+        }
+        found
+    }
+
+    pub fn extend_right_while_indexed<F>(&mut self, mut func: F)
+    where
+        F: FnMut(usize, char) -> bool,
+    {
+        let mut combined_len = 0;
+
+        for ch in self.base.as_ref()[self.end()..].chars() {
+            if func(self.view_len + combined_len, ch) {
+                combined_len += ch.len_utf8();
+            }
+            else {
+                break;
+            }
+        }
+        self.view_len += combined_len;
+    }
+
+    pub fn reduce_right_while_indexed<F>(&mut self, mut func: F)
+    where
+        F: FnMut(usize, char) -> bool,
+    {
+        let mut combined_len = 0;
+        for ch in self.as_str().chars().rev() {
+            if func(self.view_len - combined_len - ch.len_utf8(), ch) {
+                combined_len += ch.len_utf8();
+            }
+            else {
+                break;
+            }
+        }
+        self.view_len -= combined_len;
+    }
+
+    pub fn reduce_left_while_indexed<F>(&mut self, mut func: F)
+    where
+        F: FnMut(usize, char) -> bool,
+    {
+        let mut combined_len = 0;
+        for ch in self.as_str().chars() {
+            if func(combined_len, ch) {
+                combined_len += ch.len_utf8();
+            }
+            else {
+                break;
+            }
+        }
+        self.view_start += combined_len;
+        self.view_len -= combined_len;
+    }
+
+    pub fn extend_right_until_contains(
+        &mut self,
+        needle: &str,
+    ) -> Result<(), BaseStringIsTooShort> {
+        let needle_idx = self.base.as_ref()[self.start()..]
+            .find(needle)
+            .ok_or(BaseStringIsTooShort { side: Side::Right })?;
+
+        self.view_len = self.view_len.max(needle_idx + needle.len());
+        Ok(())
+    }
+
+    pub fn extend_right_saturating(&mut self, n: usize) -> usize {
+        let mut extended = 0;
+        let mut combined_len = 0;
+        for ch in self.base.as_ref()[self.end()..].chars().take(n) {
+            extended += 1;
+            combined_len += ch.len_utf8();
+        }
+        self.view_len += combined_len;
+        extended
+    }
+
+    pub fn extend_left_saturating(&mut self, n: usize) -> usize {
+        let mut extended = 0;
+        let mut combined_len = 0;
+        for ch in self.base.as_ref()[..self.start()].chars().rev().take(n) {
+            extended += 1;
+            combined_len += ch.len_utf8();
+        }
+        self.view_start -= combined_len;
+        self.view_len += combined_len;
+        extended
+    }
+
+    pub fn reduce_right_saturating(&mut self, n: usize) -> usize {
+        let mut reduced = 0;
+        let mut combined_len = 0;
+        for ch in self.as_str().chars().rev().take(n) {
+            reduced += 1;
+            combined_len += ch.len_utf8();
+        }
+        self.view_len -= combined_len;
+        reduced
+    }
+
+    pub fn reduce_left_saturating(&mut self, n: usize) -> usize {
+        let mut reduced = 0;
+        let mut combined_len = 0;
+        for ch in self.as_str().chars().take(n) {
+            reduced += 1;
+            combined_len += ch.len_utf8();
+        }
+        self.view_start += combined_len;
+        self.view_len -= combined_len;
+        reduced
+    }
+
+    pub fn char_idx_at_column(&self, column: usize) -> Option<usize> {
+        let mut char_column = 0;
+        for (idx, ch) in self.as_str().chars().enumerate() {
+            char_column += crate::char_display_width(ch);
+            if column < char_column {
+                return Some(idx);
+            }
+        }
+        None
+    }
+
+    pub fn escape_debug_len(&self) -> usize {
+        self.as_str()
+            .chars()
+            .flat_map(char::escape_debug)
+            .map(char::len_utf8)
+            .sum()
+    }
+
+    pub fn chars_eq_by<F: FnMut(char, char) -> bool>(&self, other: &str, mut cmp: F) -> bool {
+        let mut chars = self.as_str().chars();
+        let mut other = other.chars();
+
+        loop {
+            match (chars.next(), other.next()) {
+                (Some(a), Some(b)) if cmp(a, b) => {}
+                (None, None) => return true,
+                _ => return false,
+            }
+        }
+    }
 }
@@ -5,6 +5,9 @@ use string_view::StrExt;
 
 use crate as string_view;
 
+mod str_ext;
+mod view;
+
 #[test]
 fn chars_in_place() {
     let mut iter = "Hello".chars_in_place();
@@ -16,6 +19,16 @@ fn chars_in_place() {
     assert_eq!(iter.next().unwrap(), 'o');
 }
 
+#[test]
+fn chars_in_place_multibyte() {
+    let mut iter = "é本😀".chars_in_place();
+
+    assert_eq!(iter.next().unwrap(), "é");
+    assert_eq!(iter.next().unwrap(), "本");
+    assert_eq!(iter.next().unwrap(), "😀");
+    assert!(iter.next().is_none());
+}
+
 #[test]
 fn chars_in_place_mut() {
     let text: &mut str = &mut String::from("Hello");
@@ -52,3 +65,323 @@ fn chars_in_place_mut_rev() {
     assert_eq!(iter.next().unwrap(), "e");
     assert_eq!(iter.next().unwrap(), "H");
 }
+
+#[test]
+fn replace_error_sizes() {
+    let text: &mut str = &mut String::from("aПb");
+
+    let mut iter = text.chars_in_place_mut();
+
+    let err = iter.next().unwrap().replace('本').unwrap_err();
+    assert_eq!(err.expected, 1);
+    assert_eq!(err.found, 3);
+
+    let err = iter.next().unwrap().replace('b').unwrap_err();
+    assert_eq!(err.expected, 2);
+    assert_eq!(err.found, 1);
+
+    assert_eq!(
+        err.to_string(),
+        "Unable to replace character because they have different sizes: \
+        expected 2 bytes, got 1. \
+        Characters have to have the same size for in-place modification."
+    );
+}
+
+#[test]
+fn change_case_error_sizes() {
+    let text: &mut str = &mut String::from("ßİ");
+
+    let mut iter = text.chars_in_place_mut();
+
+    // 'ß' is uppercased to "SS"
+    let err = iter.next().unwrap().make_uppercase().unwrap_err();
+    assert_eq!(err.expected, 2);
+    assert_eq!(err.found, 2);
+
+    // 'İ' is lowercased to "i̇"
+    let err = iter.next().unwrap().make_lowercase().unwrap_err();
+    assert_eq!(err.expected, 2);
+    assert_eq!(err.found, 3);
+
+    assert_eq!(text, "ßİ");
+}
+
+#[test]
+fn char_bytes() {
+    let text = "a€";
+    let mut iter = text.chars_in_place();
+
+    let ch = iter.next().unwrap();
+    assert_eq!(ch.as_bytes(), b"a");
+
+    let ch = iter.next().unwrap();
+    assert_eq!(ch.as_bytes(), [0xE2, 0x82, 0xAC]);
+    assert_eq!(ch.bytes().collect::<Vec<u8>>(), [0xE2, 0x82, 0xAC]);
+
+    let text: &mut str = &mut String::from("a€");
+    let mut iter = text.chars_in_place_mut();
+
+    assert_eq!(iter.next().unwrap().bytes().collect::<Vec<u8>>(), b"a");
+    assert_eq!(iter.next().unwrap().as_bytes(), "€".as_bytes());
+}
+
+#[test]
+fn chars_in_place_nth() {
+    for text in ["Hello World", "aé本€🦀b", ""] {
+        for n in 0..8 {
+            let mut iter = text.chars_in_place();
+            let mut std_iter = text.chars();
+
+            assert_eq!(iter.nth(n).map(|ch| ch.char()), std_iter.nth(n));
+
+            // Iterator stays correctly positioned after nth
+            let rest: Vec<char> = iter.map(|ch| ch.char()).collect();
+            let std_rest: Vec<char> = std_iter.collect();
+            assert_eq!(rest, std_rest);
+        }
+    }
+}
+
+#[test]
+fn chars_in_place_nth_multibyte() {
+    let mut iter = "aé本€🦀b".chars_in_place();
+
+    assert_eq!(iter.nth(3).unwrap(), "€");
+    assert_eq!(iter.nth(1).unwrap(), "b");
+    assert!(iter.nth(1).is_none());
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn replace_with() {
+    let text: &mut str = &mut String::from("abc");
+
+    for mut ch in text.chars_in_place_mut() {
+        ch.replace_with(|ch| (ch as u8 + 1) as char).unwrap();
+    }
+    assert_eq!(text, "bcd");
+
+    let text: &mut str = &mut String::from("aПb");
+    let mut iter = text.chars_in_place_mut();
+
+    let err = iter.next().unwrap().replace_with(|_| 'Ж').unwrap_err();
+    assert_eq!((err.expected, err.found), (1, 2));
+
+    iter.next().unwrap().replace_with(|_| 'Ж').unwrap();
+    assert_eq!(text, "aЖb");
+}
+
+#[test]
+fn toggle_ascii_case() {
+    let mut text = String::from("aZ@[`{é");
+
+    let toggled: Vec<String> = text
+        .chars_in_place_mut()
+        .map(|mut ch| {
+            ch.toggle_ascii_case();
+            ch.as_str().to_string()
+        })
+        .collect();
+
+    assert_eq!(toggled, ["A", "z", "@", "[", "`", "{", "é"]);
+    assert_eq!(text, "Az@[`{é");
+}
+
+#[test]
+fn set_ascii() {
+    let text: &mut str = &mut String::from("abc");
+    text.chars_in_place_mut()
+        .next()
+        .unwrap()
+        .set_ascii(b'X')
+        .unwrap();
+    assert_eq!(text, "Xbc");
+
+    let text: &mut str = &mut String::from("Пb");
+    let mut chars = text.chars_in_place_mut();
+
+    let err = chars.next().unwrap().set_ascii(b'X').unwrap_err();
+    assert_eq!((err.expected, err.found), (2, 1));
+
+    let err = chars.next().unwrap().set_ascii(0xE9).unwrap_err();
+    assert_eq!((err.expected, err.found), (1, 0));
+
+    assert_eq!(text, "Пb");
+}
+
+#[test]
+fn char_eq_ignore_ascii_case() {
+    let text = "aZ1é";
+    let mut chars = text.chars_in_place();
+
+    assert!(chars.next().unwrap().eq_ignore_ascii_case('A'));
+    assert!(chars.next().unwrap().eq_ignore_ascii_case('z'));
+    assert!(chars.next().unwrap().eq_ignore_ascii_case('1'));
+
+    let ch = chars.next().unwrap();
+    assert!(ch.eq_ignore_ascii_case('é'));
+    assert!(!ch.eq_ignore_ascii_case('É'));
+}
+
+#[test]
+fn chars_in_place_remaining() {
+    let text = "héllo本";
+    let mut chars = text.chars_in_place();
+    assert_eq!(chars.remaining(), text);
+
+    chars.next();
+    chars.next_back();
+    assert_eq!(chars.remaining(), "éllo");
+
+    // Remaining string slice outlives the iterator
+    let remaining = {
+        let mut chars = text.chars_in_place();
+        chars.next();
+        chars.remaining()
+    };
+    assert_eq!(remaining, "éllo本");
+    assert_eq!(
+        text.char_idx(remaining.chars_in_place().next().unwrap()),
+        (1, 3)
+    );
+
+    let mut chars = "ab".chars_in_place();
+    chars.next();
+    chars.next();
+    assert_eq!(chars.remaining(), "");
+}
+
+#[test]
+fn char_mut_replace_str() {
+    let mut text = String::from("жук");
+    let mut ch = text.chars_in_place_mut().next().unwrap();
+
+    let err = ch.replace_str("zh!").unwrap_err();
+    assert_eq!((err.expected, err.found), (2, 3));
+
+    ch.replace_str("zh").unwrap();
+    assert_eq!(ch.as_str(), "zh");
+    assert_eq!(ch.char(), 'z');
+
+    assert_eq!(text, "zhук");
+}
+
+#[test]
+fn chars_in_place_clone_lookahead() {
+    let text = "ab->cd";
+    let mut chars = text.chars_in_place();
+    chars.next();
+    chars.next();
+
+    let mut lookahead = chars;
+    assert_eq!(lookahead.next().unwrap(), '-');
+    assert_eq!(lookahead.next().unwrap(), '>');
+
+    assert_eq!(chars.remaining(), "->cd");
+    assert_eq!(lookahead.remaining(), "cd");
+
+    assert_eq!(chars.next().unwrap(), '-');
+    assert_eq!(chars.remaining(), ">cd");
+    assert_eq!(lookahead.remaining(), "cd");
+}
+
+#[test]
+fn char_len_utf8_and_is_ascii() {
+    let text = "aж€😀";
+    let lens = text
+        .chars_in_place()
+        .map(|ch| (ch.len_utf8(), ch.is_ascii()))
+        .collect::<Vec<_>>();
+    assert_eq!(lens, [(1, true), (2, false), (3, false), (4, false)]);
+
+    let mut text = String::from("aж€😀");
+    let mut lens = Vec::new();
+    text.chars_in_place_mut().for_each(|mut ch| {
+        lens.push((ch.len_utf8(), ch.is_ascii()));
+        if ch.len_utf8() == 1 {
+            ch.replace('b').unwrap();
+        }
+    });
+    assert_eq!(lens, [(1, true), (2, false), (3, false), (4, false)]);
+    assert_eq!(text, "bж€😀");
+}
+
+#[test]
+fn char_cross_type_eq() {
+    let hello = "Hello Мир";
+    let world = String::from("World мир");
+
+    let l_hello = hello.chars_in_place().nth(2).unwrap();
+    let l_world = world.chars_in_place().nth(3).unwrap();
+    assert_eq!(l_hello, l_world);
+    assert_ne!(l_hello, world.chars_in_place().next().unwrap());
+
+    let mut world = world;
+    let mut chars = world.chars_in_place_mut();
+    let w = chars.next().unwrap();
+    assert_ne!(w, hello.chars_in_place().nth(6).unwrap());
+
+    let m = chars.nth(5).unwrap();
+    assert_eq!(m, 'м');
+    assert_ne!(m, hello.chars_in_place().nth(6).unwrap());
+    assert_eq!(
+        hello.chars_in_place().nth(7).unwrap(),
+        chars.next().unwrap()
+    );
+}
+
+#[test]
+fn char_ord_matches_char_ord() {
+    let text = "zяa€Я😀Z0本é";
+
+    let mut chars = text.chars_in_place().collect::<Vec<_>>();
+    chars.sort();
+
+    let mut expected = text.chars().collect::<Vec<_>>();
+    expected.sort();
+
+    assert_eq!(
+        chars.iter().map(|ch| ch.char()).collect::<Vec<_>>(),
+        expected
+    );
+}
+
+#[test]
+fn char_frequency_map() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let text = "абракадабра";
+
+    let mut hashed = HashMap::new();
+    for ch in text.chars_in_place() {
+        *hashed.entry(ch).or_insert(0) += 1;
+    }
+
+    let mut ordered = BTreeMap::new();
+    for ch in text.chars_in_place() {
+        *ordered.entry(ch).or_insert(0) += 1;
+    }
+
+    assert_eq!(hashed[&string_view::Char::new("а")], 5);
+    assert_eq!(hashed[&string_view::Char::new("б")], 2);
+    assert_eq!(
+        ordered
+            .into_iter()
+            .map(|(ch, n)| (ch.char(), n))
+            .collect::<Vec<_>>(),
+        [('а', 5), ('б', 2), ('д', 1), ('к', 1), ('р', 2)]
+    );
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serialize_chars() {
+    let text = "a\"本";
+
+    let json = serde_json::to_string(&text.chars_in_place().collect::<Vec<_>>()).unwrap();
+    assert_eq!(json, r#"["a","\"","本"]"#);
+
+    let parsed: Vec<char> = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, text.chars().collect::<Vec<_>>());
+}
@@ -0,0 +1,797 @@
+extern crate std;
+use std::prelude::rust_2021::*;
+
+use string_view::StrExt;
+
+use crate as string_view;
+
+#[test]
+fn center_in_place() {
+    let mut text = String::from("Hi\0\0\0\0");
+
+    text.center_in_place(2);
+    assert_eq!(text, "  Hi  ");
+}
+
+#[test]
+fn center_in_place_odd_margin() {
+    let mut text = String::from("abc1234");
+
+    text.center_in_place(3);
+    assert_eq!(text, "  abc  ");
+
+    let mut text = String::from("ab12345");
+
+    text.center_in_place(2);
+    assert_eq!(text, "  ab   ");
+}
+
+#[test]
+fn center_in_place_overwrites_multibyte_scratch() {
+    let mut text = String::from("Hi本本");
+
+    text.center_in_place(2);
+    assert_eq!(text, "   Hi   ");
+}
+
+#[test]
+fn center_in_place_full_and_empty_content() {
+    let mut text = String::from("Hello");
+    text.center_in_place(5);
+    assert_eq!(text, "Hello");
+
+    let mut text = String::from("Hello");
+    text.center_in_place(0);
+    assert_eq!(text, "     ");
+}
+
+#[test]
+#[should_panic]
+fn center_in_place_content_too_long() {
+    let mut text = String::from("Hi");
+    text.center_in_place(3);
+}
+
+#[test]
+fn windows_chars() {
+    let windows: Vec<&str> = "abcd".windows_chars(2).collect();
+    assert_eq!(windows, ["ab", "bc", "cd"]);
+
+    let windows: Vec<&str> = "abcd".windows_chars(4).collect();
+    assert_eq!(windows, ["abcd"]);
+
+    assert_eq!("abcd".windows_chars(5).next(), None);
+    assert_eq!("".windows_chars(1).next(), None);
+}
+
+#[test]
+fn windows_chars_multibyte() {
+    let windows: Vec<&str> = "aé本€b".windows_chars(3).collect();
+    assert_eq!(windows, ["aé本", "é本€", "本€b"]);
+
+    let windows: Vec<&str> = "é本".windows_chars(1).collect();
+    assert_eq!(windows, ["é", "本"]);
+}
+
+#[test]
+#[should_panic]
+fn windows_chars_zero() {
+    let _ = "abcd".windows_chars(0);
+}
+
+#[test]
+fn split_ascii_whitespace_in_place() {
+    let text = "\t the  quick\nbrown\r\n fox ";
+
+    let words: Vec<&str> = text.split_ascii_whitespace_in_place().collect();
+    assert_eq!(words, ["the", "quick", "brown", "fox"]);
+
+    assert_eq!("".split_ascii_whitespace_in_place().next(), None);
+    assert_eq!(" \n\t ".split_ascii_whitespace_in_place().next(), None);
+}
+
+#[test]
+fn split_ascii_whitespace_in_place_unicode_whitespace() {
+    let text = "a\u{A0}b c\u{2003}d";
+
+    let words: Vec<&str> = text.split_ascii_whitespace_in_place().collect();
+    assert_eq!(words, ["a\u{A0}b", "c\u{2003}d"]);
+
+    let std_words: Vec<&str> = text.split_ascii_whitespace().collect();
+    assert_eq!(words, std_words);
+}
+
+#[test]
+fn trim_matches_char_mut() {
+    let mut text = String::from("ééПриветéé");
+
+    text.trim_matches_char_mut('é').make_uppercase();
+    assert_eq!(text, "ééПРИВЕТéé");
+
+    text.trim_start_matches_char_mut('é').replace_with_char('*');
+    assert_eq!(text, "éé****************");
+
+    let mut text = String::from("ééПриветéé");
+
+    assert_eq!(text.trim_end_matches_char_mut('é'), "ééПривет");
+    assert_eq!(text.trim_start_matches_char_mut('x'), "ééПриветéé");
+    assert_eq!(text.trim_matches_char_mut('x'), "ééПриветéé");
+}
+
+#[test]
+fn count_byte() {
+    assert_eq!("Hello".count_byte(b'l'), 2);
+    assert_eq!("Hello".count_byte(b'H'), 1);
+    assert_eq!("Hello".count_byte(b'x'), 0);
+    assert_eq!("".count_byte(b'x'), 0);
+
+    // 'Ā' is encoded as [0xC4, 0x80] and 'Ѐ' as [0xD0, 0x80]
+    assert_eq!("ĀЀ".count_byte(0x80), 2);
+}
+
+#[test]
+fn count_char() {
+    let text = "日本語の本を読む本";
+
+    assert_eq!(text.count_char('本'), 3);
+    assert_eq!(text.count_char('語'), 1);
+    assert_eq!(text.count_char('a'), 0);
+    assert_eq!("Hello".count_char('l'), 2);
+}
+
+#[test]
+fn try_replace_in_place() {
+    let mut text = String::from("Привет Мир");
+
+    let result = text[13..19].try_replace_in_place("Worlds");
+    assert!(result.is_ok());
+    assert_eq!(text, "Привет Worlds");
+
+    let err = text[13..19].try_replace_in_place("Мир!").unwrap_err();
+    assert_eq!(err.expected, 6);
+    assert_eq!(err.found, 7);
+    assert_eq!(text, "Привет Worlds");
+
+    let err = text.try_replace_in_place("").unwrap_err();
+    assert_eq!((err.expected, err.found), (19, 0));
+}
+
+#[test]
+#[should_panic]
+fn replace_in_place_different_size() {
+    let mut text = String::from("Hello");
+    text.replace_in_place("Hi");
+}
+
+#[test]
+fn line_indices() {
+    let lines: Vec<(usize, &str)> = "a\nbb\nccc".line_indices().collect();
+    assert_eq!(lines, [(0, "a"), (2, "bb"), (5, "ccc")]);
+}
+
+#[test]
+fn line_indices_match_lines() {
+    let texts = [
+        "",
+        "\n",
+        "\n\n",
+        "a\r\nb\r\n",
+        "a\rb\r",
+        "\r\n\r\nПривет\n\nМир\n",
+    ];
+    for text in texts {
+        let lines: Vec<&str> = text.line_indices().map(|(_, line)| line).collect();
+        let std_lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines, std_lines);
+
+        for (idx, line) in text.line_indices() {
+            assert_eq!(&text[idx..idx + line.len()], line);
+        }
+    }
+}
+
+#[test]
+fn line_indices_crlf() {
+    let lines: Vec<(usize, &str)> = "a\r\n\r\nПривет\r\n".line_indices().collect();
+    assert_eq!(lines, [(0, "a"), (3, ""), (5, "Привет")]);
+}
+
+#[test]
+fn repeat_into() {
+    let mut buf = String::from("12345");
+
+    assert_eq!("ab".repeat_into(&mut buf), "ababa");
+    assert_eq!(buf, "ababa");
+
+    assert_eq!("abcdefg".repeat_into(&mut buf), "abcde");
+    assert_eq!("".repeat_into(&mut buf), "");
+    assert_eq!(buf, "     ");
+}
+
+#[test]
+fn repeat_into_multibyte() {
+    let mut buf = String::from("12345");
+
+    let filled = "é".repeat_into(&mut buf);
+    assert_eq!(filled, "éé");
+    assert_eq!(filled.len(), 4);
+    assert_eq!(buf, "éé ");
+
+    // Old multi-byte characters are not left half-overwritten
+    let mut buf = String::from("ab本");
+    assert_eq!("é".repeat_into(&mut buf), "éé");
+    assert_eq!(buf, "éé ");
+
+    let mut buf = String::from("12345678");
+    assert_eq!("a本".repeat_into(&mut buf), "a本a本");
+    assert_eq!("本a".repeat_into(&mut buf), "本a本a");
+    assert_eq!("a本".repeat_into(&mut buf[..7]), "a本a");
+}
+
+#[test]
+fn char_boundaries() {
+    let boundaries: Vec<usize> = "aé".char_boundaries().collect();
+    assert_eq!(boundaries, [0, 1, 3]);
+
+    let boundaries: Vec<usize> = "".char_boundaries().collect();
+    assert_eq!(boundaries, [0]);
+
+    let text = "Привет, 世界! 🦀";
+    let boundaries: Vec<usize> = text.char_boundaries().collect();
+    let std_boundaries: Vec<usize> = (0..=text.len())
+        .filter(|&idx| text.is_char_boundary(idx))
+        .collect();
+    assert_eq!(boundaries, std_boundaries);
+}
+
+#[test]
+fn split_keep_delim() {
+    let runs: Vec<&str> = "a, b".split_keep_delim(|ch| ch == ',').collect();
+    assert_eq!(runs, ["a", ",", " b"]);
+
+    let runs: Vec<&str> = ",a,,b,".split_keep_delim(|ch| ch == ',').collect();
+    assert_eq!(runs, [",", "a", ",,", "b", ","]);
+
+    assert_eq!("".split_keep_delim(|ch| ch == ',').next(), None);
+}
+
+#[test]
+fn split_keep_delim_round_trip() {
+    let texts = ["a, b", "  Привет,  мир!  ", ", ,", "本"];
+
+    for text in texts {
+        let mut is_delim = None;
+        let mut restored = String::new();
+
+        for run in text.split_keep_delim(|ch| ch == ',' || ch.is_whitespace()) {
+            assert!(!run.is_empty());
+
+            // Runs alternate between delimiters and content
+            let run_is_delim = run.starts_with(|ch: char| ch == ',' || ch.is_whitespace());
+            assert_ne!(is_delim, Some(run_is_delim));
+            is_delim = Some(run_is_delim);
+
+            restored.push_str(run);
+        }
+        assert_eq!(restored, text);
+    }
+}
+
+#[test]
+fn match_delimiter() {
+    let text = "(a(b)c)d";
+
+    let view = text.match_delimiter('(', ')').unwrap();
+    assert_eq!(view.as_str(), "(a(b)c)");
+    assert_eq!((view.start(), view.end()), (0, 7));
+
+    assert_eq!(text[2..].match_delimiter('(', ')').unwrap().as_str(), "(b)");
+    assert_eq!("()".match_delimiter('(', ')').unwrap().as_str(), "()");
+
+    assert!("(a(b)c".match_delimiter('(', ')').is_none());
+    assert!("a(b)".match_delimiter('(', ')').is_none());
+    assert!("".match_delimiter('(', ')').is_none());
+}
+
+#[test]
+fn match_delimiter_multibyte() {
+    let text = "«a«б»в»г";
+
+    let view = text.match_delimiter('«', '»').unwrap();
+    assert_eq!(view.as_str(), "«a«б»в»");
+
+    let text = "\"quoted\" rest";
+    let view = text.match_delimiter('"', '"').unwrap();
+    assert_eq!(view.as_str(), "\"quoted\"");
+}
+
+#[test]
+fn for_each_line_mut() {
+    let mut text = String::from("first\nsecond\r\n\nпривет\r\nlast");
+
+    text.for_each_line_mut(|line| line.make_uppercase());
+    assert_eq!(text, "FIRST\nSECOND\r\n\nПРИВЕТ\r\nLAST");
+}
+
+#[test]
+fn for_each_line_mut_matches_lines() {
+    let texts = ["", "\n", "a\r\nb\r\n", "a\rb\r", "\r\n\r\nПривет\n\nМир\n"];
+
+    for text in texts {
+        let mut buf = String::from(text);
+        let mut lines = Vec::new();
+
+        buf.for_each_line_mut(|line| lines.push(String::from(&*line)));
+
+        let std_lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines, std_lines);
+    }
+}
+
+#[test]
+fn normalize_newlines_in_place() {
+    let mut text = String::from("a\r\nb\r\nc");
+
+    let normalized = text.normalize_newlines_in_place();
+    assert_eq!(normalized, "a\nb\nc");
+    assert_eq!(normalized.len(), 5);
+    assert_eq!(text, "a\nb\nc  ");
+}
+
+#[test]
+fn normalize_newlines_in_place_lone_cr() {
+    let mut text = String::from("\r\rПривет\r\r\nМир\r\n\r");
+
+    assert_eq!(text.normalize_newlines_in_place(), "\r\rПривет\r\nМир\n\r");
+
+    let mut text = String::from("no newlines");
+    assert_eq!(text.normalize_newlines_in_place(), "no newlines");
+
+    let mut text = String::from("\r\n");
+    assert_eq!(text.normalize_newlines_in_place(), "\n");
+    assert_eq!(text, "\n ");
+}
+
+#[test]
+fn dedent_in_place() {
+    let mut text = String::from("    first\n      second\n    third");
+
+    let dedented = text.dedent_in_place();
+    assert_eq!(dedented, "first\n  second\nthird");
+    assert_eq!(text, "first\n  second\nthird            ");
+}
+
+#[test]
+fn dedent_in_place_blank_lines() {
+    let mut text = String::from("\n    a\r\n  \r\n\n        b\n \n    c\n");
+
+    assert_eq!(text.dedent_in_place(), "\na\r\n\r\n\n    b\n\nc\n");
+
+    let mut text = String::from("   \n  \n");
+    assert_eq!(text.dedent_in_place(), "\n\n");
+}
+
+#[test]
+fn dedent_in_place_mixed_indent() {
+    // Only common part of indentation is removed
+    let mut text = String::from("\t  a\n\t b\n");
+    assert_eq!(text.dedent_in_place(), " a\nb\n");
+
+    let mut text = String::from("\u{3000}\u{3000}a\n\u{3000}b");
+    assert_eq!(text.dedent_in_place(), "\u{3000}a\nb");
+
+    let mut text = String::from("a\n    b");
+    assert_eq!(text.dedent_in_place(), "a\n    b");
+}
+
+#[test]
+fn char_at_char_idx() {
+    let text = "aé本";
+
+    let chars: Vec<(&str, usize)> = (0..4)
+        .filter_map(|n| text.char_at_char_idx(n))
+        .map(|(ch, idx)| (ch.as_str(), idx))
+        .collect();
+    assert_eq!(chars, [("a", 0), ("é", 1), ("本", 3)]);
+
+    let (ch, idx) = text.char_at_char_idx(1).unwrap();
+    let ch_len = ch.as_str().len();
+    assert_eq!(text.char_idx(ch), (idx, idx + ch_len));
+
+    assert!("".char_at_char_idx(0).is_none());
+}
+
+#[test]
+fn trim_matches_str_mut() {
+    let mut text = String::from("abab text abab");
+    assert_eq!(text.trim_matches_str_mut("ab"), " text ");
+
+    let mut text = String::from("ababa");
+    assert_eq!(text.trim_matches_str_mut("ab"), "a");
+
+    let mut text = String::from("本本本");
+    assert_eq!(text.trim_matches_str_mut("本"), "");
+
+    let mut text = String::from("text");
+    assert_eq!(text.trim_matches_str_mut(""), "text");
+
+    let mut text = String::from("--x--");
+    text.trim_matches_str_mut("-").make_ascii_uppercase();
+    assert_eq!(text, "--X--");
+}
+
+#[test]
+fn split_at_boundary_after() {
+    let text = "aé本";
+
+    let splits: Vec<(&str, &str)> = (0..8)
+        .map(|byte| text.split_at_boundary_after(byte))
+        .collect();
+    assert_eq!(
+        splits,
+        [
+            ("", "aé本"),
+            ("a", "é本"),
+            ("aé", "本"),
+            ("aé", "本"),
+            ("aé本", ""),
+            ("aé本", ""),
+            ("aé本", ""),
+            ("aé本", ""),
+        ]
+    );
+
+    assert_eq!("".split_at_boundary_after(0), ("", ""));
+    assert_eq!("".split_at_boundary_after(10), ("", ""));
+}
+
+#[test]
+fn is_sorted_chars() {
+    assert!("abcz".is_sorted_chars());
+    assert!("aabbb".is_sorted_chars());
+    assert!("aéя本".is_sorted_chars());
+    assert!("".is_sorted_chars());
+    assert!("x".is_sorted_chars());
+
+    assert!(!"acb".is_sorted_chars());
+    assert!(!"本a".is_sorted_chars());
+}
+
+#[test]
+fn is_sorted_chars_by_short_circuits() {
+    let mut compared = Vec::new();
+
+    let sorted = "abdcef".is_sorted_chars_by(|a, b| {
+        compared.push((a, b));
+        a <= b
+    });
+
+    assert!(!sorted);
+    assert_eq!(compared, [('a', 'b'), ('b', 'd'), ('d', 'c')]);
+}
+
+#[test]
+#[cfg(feature = "width")]
+fn display_width() {
+    assert_eq!("abc".display_width(), 3);
+    assert_eq!("本".display_width(), 2);
+    assert_eq!("a本b".display_width(), 4);
+    assert_eq!("日本語".display_width(), 6);
+    assert_eq!("\u{7}a".display_width(), 1);
+
+    let text = "<a本b>";
+    assert_eq!(text.view_part(1, text.len() - 1).display_width(), 4);
+}
+
+#[test]
+#[cfg(not(feature = "width"))]
+fn display_width_counts_chars() {
+    assert_eq!("abc".display_width(), 3);
+    assert_eq!("a本b".display_width(), 3);
+
+    let text = "<a本b>";
+    assert_eq!(text.view_part(1, text.len() - 1).display_width(), 3);
+}
+
+#[test]
+fn strip_ansi_in_place() {
+    let mut text = String::from("\x1b[31mred\x1b[0m");
+    assert_eq!(text.strip_ansi_in_place(), "red");
+    assert_eq!(text, "red         ");
+
+    let mut text = String::from("\x1b[1;4mжирный\x1b[m и \x1b[2K本");
+    assert_eq!(text.strip_ansi_in_place(), "жирный и 本");
+
+    let mut text = String::from("no escapes");
+    assert_eq!(text.strip_ansi_in_place(), "no escapes");
+}
+
+#[test]
+fn strip_ansi_in_place_unterminated() {
+    let mut text = String::from("a\x1b[31");
+    assert_eq!(text.strip_ansi_in_place(), "a\x1b[31");
+
+    let mut text = String::from("\x1b[3é\x1b]0;title\x07\x1b");
+    assert_eq!(text.strip_ansi_in_place(), "\x1b[3é\x1b]0;title\x07\x1b");
+}
+
+#[test]
+fn ascii_byte_counts() {
+    let mut counts = [0; 256];
+
+    "aabbb".ascii_byte_counts(&mut counts);
+    assert_eq!(counts[b'a' as usize], 2);
+    assert_eq!(counts[b'b' as usize], 3);
+    assert_eq!(counts.iter().sum::<usize>(), 5);
+
+    // Counts are accumulated
+    "aé".ascii_byte_counts(&mut counts);
+    assert_eq!(counts[b'a' as usize], 3);
+    assert_eq!(counts[0xC3], 1);
+    assert_eq!(counts[0xA9], 1);
+    assert_eq!(counts.iter().sum::<usize>(), 8);
+}
+
+#[test]
+fn nth_line() {
+    let text = "first\nsecond\r\n\nfourth\n";
+
+    assert_eq!(text.nth_line(0), Some("first"));
+    assert_eq!(text.nth_line(1), Some("second"));
+    assert_eq!(text.nth_line(2), Some(""));
+    assert_eq!(text.nth_line(3), Some("fourth"));
+    assert_eq!(text.nth_line(4), None);
+
+    assert_eq!("".nth_line(0), None);
+}
+
+#[test]
+fn trim_end_newline_mut() {
+    let mut text = String::from("line\r\n");
+    assert_eq!(text.trim_end_newline_mut(), "line");
+
+    let mut text = String::from("line\n");
+    assert_eq!(text.trim_end_newline_mut(), "line");
+
+    let mut text = String::from("line\r");
+    assert_eq!(text.trim_end_newline_mut(), "line");
+
+    let mut text = String::from("line");
+    assert_eq!(text.trim_end_newline_mut(), "line");
+
+    // Only a single terminator is removed
+    let mut text = String::from("line\n\r\n");
+    assert_eq!(text.trim_end_newline_mut(), "line\n");
+
+    let mut text = String::from("line \t\n");
+    assert_eq!(text.trim_end_newline_mut(), "line \t");
+
+    let mut text = String::from("");
+    assert_eq!(text.trim_end_newline_mut(), "");
+}
+
+#[test]
+fn ascii_number_spans() {
+    let text = "a12b345";
+
+    let numbers: Vec<(&str, usize, usize)> = text
+        .ascii_number_spans()
+        .map(|number| (number.as_str(), number.start(), number.end()))
+        .collect();
+    assert_eq!(numbers, [("12", 1, 3), ("345", 4, 7)]);
+
+    let numbers: Vec<&str> = "0 ٣ 1é23本"
+        .ascii_number_spans()
+        .map(|number| number.as_str())
+        .collect();
+    assert_eq!(numbers, ["0", "1", "23"]);
+
+    assert!("no digits".ascii_number_spans().next().is_none());
+    assert!("".ascii_number_spans().next().is_none());
+}
+
+#[test]
+fn byte_count_newlines() {
+    let text = "one\ntwo\nthree\n";
+    assert_eq!(text.byte_count(b'\n'), 3);
+    assert_eq!(text.byte_count(b'\n'), text.lines().count());
+
+    // Without trailing newline the last line is not terminated
+    let text = "one\ntwo\nthree";
+    assert_eq!(text.byte_count(b'\n'), text.lines().count() - 1);
+
+    assert_eq!("".byte_count(b'\n'), 0);
+    assert_eq!("日本".byte_count(b'\n'), 0);
+}
+
+#[test]
+fn collapse_trailing_newlines_in_place() {
+    let mut text = String::from("text\n\n\n");
+    assert_eq!(text.collapse_trailing_newlines_in_place(), "text\n");
+    assert_eq!(text, "text\n  ");
+
+    let mut text = String::from("text");
+    assert_eq!(text.collapse_trailing_newlines_in_place(), "text");
+
+    let mut text = String::from("a\n\nb\n");
+    assert_eq!(text.collapse_trailing_newlines_in_place(), "a\n\nb\n");
+
+    let mut text = String::from("text\r\n\n\r\n");
+    assert_eq!(text.collapse_trailing_newlines_in_place(), "text\r\n");
+
+    let mut text = String::from("text\n\r\n");
+    assert_eq!(text.collapse_trailing_newlines_in_place(), "text\n");
+
+    // Lone `\r` is not a line terminator
+    let mut text = String::from("text\r\r\n");
+    assert_eq!(text.collapse_trailing_newlines_in_place(), "text\r\r\n");
+
+    let mut text = String::from("\n\n");
+    assert_eq!(text.collapse_trailing_newlines_in_place(), "\n");
+}
+
+#[test]
+fn view_range() {
+    let text = "Привет World";
+
+    let view = text.view_range(13..);
+    assert_eq!((view.as_str(), view.start(), view.end()), ("World", 13, 18));
+
+    assert_eq!(text.view_range(..12).as_str(), "Привет");
+    assert_eq!(text.view_range(2..=5).as_str(), "ри");
+    assert_eq!(text.view_range(..).as_str(), text);
+    assert_eq!(text.view_range(18..).as_str(), "");
+
+    let mut text = String::from("Привет World");
+    let mut view = text.view_range_mut(..12);
+    view.extend_right(1);
+    assert_eq!(view.as_str(), "Привет ");
+}
+
+#[test]
+#[should_panic(expected = "does not lie on character boundaries")]
+fn view_range_not_on_boundary() {
+    let _ = "Привет".view_range(1..);
+}
+
+#[test]
+#[should_panic(expected = "is out of bounds")]
+fn view_range_out_of_bounds() {
+    let mut text = String::from("Hello");
+    let _ = text.view_range_mut(..6);
+}
+
+#[test]
+fn split_at_char_checked() {
+    assert_eq!("abc".split_at_char_checked(2), Some(("ab", "c")));
+    assert_eq!("abc".split_at_char_checked(3), Some(("abc", "")));
+    assert_eq!("abc".split_at_char_checked(0), Some(("", "abc")));
+    assert_eq!("abc".split_at_char_checked(4), None);
+    assert_eq!("abc".split_at_char_checked(5), None);
+
+    assert_eq!("aé本".split_at_char_checked(2), Some(("aé", "本")));
+    assert_eq!("".split_at_char_checked(0), Some(("", "")));
+    assert_eq!("".split_at_char_checked(1), None);
+}
+
+#[test]
+fn char_chunks_mut() {
+    let mut text = String::from("abcdefg");
+
+    for (idx, chunk) in text.char_chunks_mut(3).enumerate() {
+        if idx % 2 == 0 {
+            chunk.make_ascii_uppercase();
+        }
+    }
+    assert_eq!(text, "ABCdefG");
+
+    let mut text = String::from("aé本ж");
+    let chunks: Vec<String> = text
+        .char_chunks_mut(2)
+        .map(|chunk| chunk.to_string())
+        .collect();
+    assert_eq!(chunks, ["aé", "本ж"]);
+
+    let chunks: Vec<String> = text
+        .char_chunks_mut(10)
+        .map(|chunk| chunk.to_string())
+        .collect();
+    assert_eq!(chunks, ["aé本ж"]);
+
+    assert!(String::new().char_chunks_mut(1).next().is_none());
+}
+
+#[test]
+#[should_panic(expected = "Chunk size cannot be zero")]
+fn char_chunks_mut_zero() {
+    let mut text = String::from("abc");
+    let _ = text.char_chunks_mut(0);
+}
+
+#[test]
+fn nth_word() {
+    let text = "  the quick\tbrown\n\nfox ";
+
+    assert_eq!(text.nth_word(0), Some("the"));
+    assert_eq!(text.nth_word(1), Some("quick"));
+    assert_eq!(text.nth_word(2), Some("brown"));
+    assert_eq!(text.nth_word(3), Some("fox"));
+    assert_eq!(text.nth_word(4), None);
+
+    assert_eq!("   ".nth_word(0), None);
+    assert_eq!("слово\u{3000}ещё".nth_word(1), Some("ещё"));
+}
+
+#[test]
+fn replace_with_char_multibyte() {
+    let mut text = String::from("abcdef");
+    text.replace_with_char('ж');
+    assert_eq!(text, "жжж");
+
+    let mut text = String::from("Мир!!!");
+    text.replace_with_char('本');
+    assert_eq!(text, "本本本");
+}
+
+#[test]
+fn partition_in_place() {
+    let mut text = String::from("a1b2c3");
+    assert_eq!(text.partition_in_place(char::is_alphabetic).ok(), Some(3));
+    assert_eq!(text, "abc123");
+
+    let mut text = String::from("1а2б3в");
+    assert!(text.partition_in_place(char::is_alphabetic).is_err());
+    assert_eq!(text, "1а2б3в");
+
+    let mut text = String::from("жЖзЗиИ");
+    assert_eq!(text.partition_in_place(char::is_uppercase).ok(), Some(6));
+    assert_eq!(text, "ЖЗИжзи");
+
+    let mut text = String::from("abc");
+    assert_eq!(text.partition_in_place(|_| false).ok(), Some(0));
+    assert_eq!(text, "abc");
+
+    let mut text = String::new();
+    assert_eq!(text.partition_in_place(|_| true).ok(), Some(0));
+}
+
+#[test]
+fn count_occurrences() {
+    assert_eq!("ababab".count_occurrences("ab"), 3);
+    assert_eq!("aaa".count_occurrences("aa"), 1);
+    assert_eq!("aaaa".count_occurrences("aa"), 2);
+    assert_eq!("мир мир".count_occurrences("ир"), 2);
+    assert_eq!("abc".count_occurrences("abcd"), 0);
+    assert_eq!("".count_occurrences(""), 1);
+}
+
+#[test]
+fn lines_take() {
+    let text = "one\ntwo\r\nthree\n\nfive\n";
+
+    assert_eq!(text.lines_take(2).collect::<Vec<_>>(), ["one", "two"]);
+    assert_eq!(text.lines_take(0).count(), 0);
+    assert_eq!(
+        text.lines_take(10).collect::<Vec<_>>(),
+        ["one", "two", "three", "", "five"]
+    );
+    assert_eq!("".lines_take(3).count(), 0);
+}
+
+#[test]
+fn non_whitespace_idx() {
+    assert_eq!("  hi  ".first_non_whitespace_idx(), Some(2));
+    assert_eq!("  hi  ".last_non_whitespace_idx(), Some(3));
+
+    let text = "\u{3000}本 x\n";
+    assert_eq!(text.first_non_whitespace_idx(), Some(3));
+    assert_eq!(text.last_non_whitespace_idx(), Some(7));
+
+    assert_eq!("a".first_non_whitespace_idx(), Some(0));
+    assert_eq!("a".last_non_whitespace_idx(), Some(0));
+
+    for text in ["", "   ", "\t\r\n\u{A0}"] {
+        assert_eq!(text.first_non_whitespace_idx(), None);
+        assert_eq!(text.last_non_whitespace_idx(), None);
+    }
+}
@@ -0,0 +1,1537 @@
+extern crate std;
+use std::prelude::rust_2021::*;
+
+use string_view::{StrExt, StringView, StringViewMut};
+
+use crate as string_view;
+
+#[test]
+fn rchar_indices() {
+    let text = "(a[é]本)";
+    let view = text.view_part(1, text.len() - 1);
+    assert_eq!(view.as_str(), "a[é]本");
+
+    let chars: Vec<(usize, &str)> = view
+        .rchar_indices()
+        .map(|(idx, ch)| (idx, ch.as_str()))
+        .collect();
+
+    assert_eq!(chars, [(6, "本"), (5, "]"), (3, "é"), (2, "["), (1, "a")]);
+}
+
+#[test]
+fn rchar_indices_match_char_indices() {
+    let text = "Привет, 世界!";
+    let view = text.view_part(2, text.len());
+
+    let mut forward: Vec<(usize, &str)> = view
+        .char_indices_in_place()
+        .map(|(idx, ch)| (idx, ch.as_str()))
+        .collect();
+    forward.reverse();
+
+    let backward: Vec<(usize, &str)> = view
+        .rchar_indices()
+        .map(|(idx, ch)| (idx, ch.as_str()))
+        .collect();
+
+    assert_eq!(forward, backward);
+
+    for (idx, ch) in backward {
+        assert_eq!(&text[idx..idx + ch.len()], ch);
+    }
+}
+
+#[test]
+fn char_indices_in_place_both_ends() {
+    let text = "abcd";
+    let mut chars = text.view().char_indices_in_place();
+
+    assert_eq!(chars.next_back().map(|(idx, _)| idx), Some(3));
+    assert_eq!(chars.next().map(|(idx, _)| idx), Some(0));
+    assert_eq!(chars.next_back().map(|(idx, _)| idx), Some(2));
+    assert_eq!(chars.next().map(|(idx, _)| idx), Some(1));
+    assert!(chars.next().is_none());
+    assert!(chars.next_back().is_none());
+}
+
+#[test]
+fn str_prefix_multibyte() {
+    let text = "<aé本€>";
+    let view = text.view_part(1, text.len() - 1);
+    assert_eq!(view.as_str(), "aé本€");
+
+    assert_eq!(view.str_prefix(0), "");
+    assert_eq!(view.str_prefix(1), "a");
+    assert_eq!(view.str_prefix(2), "aé");
+    assert_eq!(view.str_prefix(3), "aé本");
+    assert_eq!(view.str_prefix(4), "aé本€");
+    assert_eq!(view.str_prefix(5), "aé本€");
+}
+
+#[test]
+fn str_suffix_multibyte() {
+    let text = "<aé本€>";
+    let view = text.view_part(1, text.len() - 1);
+
+    assert_eq!(view.str_suffix(0), "");
+    assert_eq!(view.str_suffix(1), "€");
+    assert_eq!(view.str_suffix(2), "本€");
+    assert_eq!(view.str_suffix(3), "é本€");
+    assert_eq!(view.str_suffix(4), "aé本€");
+    assert_eq!(view.str_suffix(5), "aé本€");
+}
+
+#[test]
+fn fold_chars() {
+    let text = "<aé本>";
+    let view = text.view_part(1, text.len() - 1);
+
+    assert_eq!(view.fold_chars(0, |acc, ch| acc + ch.len_utf8()), 6);
+    assert_eq!(view.fold_chars(0, |acc, _| acc + 1), 3);
+
+    let mut text = String::from("<aé本>");
+    let len = text.len();
+    let view = text.view_part_mut(1, len - 1);
+
+    assert_eq!(view.fold_chars(0, |acc, ch| acc + ch.len_utf8()), 6);
+}
+
+#[test]
+fn extend_both_while() {
+    let text = "Hello World";
+
+    let mut view = text.view_part(2, 3);
+    assert_eq!(view.as_str(), "l");
+
+    view.extend_both_while(char::is_alphabetic);
+    assert_eq!(view.as_str(), "Hello");
+    assert_eq!((view.start(), view.end()), (0, 5));
+
+    let mut view = text.view_part(5, 5);
+    view.extend_both_while(char::is_alphabetic);
+    assert_eq!(view.as_str(), "Hello");
+
+    let mut view = text.view_part(5, 6);
+    view.extend_both_while(char::is_alphabetic);
+    assert_eq!(view.as_str(), "Hello World");
+}
+
+#[test]
+fn is_blank() {
+    let text = "Hello \t\n\u{A0} World";
+
+    let view = text.view_part(5, 11);
+    assert_eq!(view.as_str(), " \t\n\u{A0} ");
+    assert!(view.is_blank());
+    assert!(!view.is_empty());
+
+    let view = text.view_part(5, 12);
+    assert!(!view.is_blank());
+
+    let view = text.view_part(3, 3);
+    assert!(view.is_blank());
+    assert!(view.is_empty());
+
+    let mut text = String::from("Hello \t World");
+    let mut view = text.view_part_mut(5, 8);
+    assert!(view.is_blank());
+
+    view.reduce_left(1);
+    view.reduce_right(1);
+    assert!(view.is_blank());
+
+    view.shrink_to_left();
+    assert!(view.is_empty());
+}
+
+#[test]
+fn into_trimmed() {
+    let mut text = String::from("[ \t Hello \n]");
+    let len = text.len();
+
+    let mut view = text.view_part_mut(1, len - 1).into_trimmed();
+    assert_eq!(view.as_str(), "Hello");
+    assert_eq!((view.start(), view.end()), (4, 9));
+
+    view.extend_left(4);
+    assert_eq!(view.as_str(), "[ \t Hello");
+
+    view.extend_right_while(|_| true);
+    assert_eq!(view.as_str(), "[ \t Hello \n]");
+}
+
+#[test]
+fn first_difference() {
+    let old = "foobar".view();
+    let new = "foobaz".view();
+    assert_eq!(old.first_difference(&new), Some(5));
+    assert_eq!(new.first_difference(&old), Some(5));
+
+    let short = "foo".view();
+    assert_eq!(old.first_difference(&short), Some(3));
+    assert_eq!(short.first_difference(&old), Some(3));
+
+    let empty = "".view();
+    assert_eq!(empty.first_difference(&empty), None);
+    assert_eq!(empty.first_difference(&old), Some(0));
+}
+
+#[test]
+fn first_difference_windows() {
+    let text = "[Привет] [Привед]";
+
+    let first = text.view_part(1, 13);
+    let second = text.view_part(16, 28);
+    assert_eq!(first.as_str(), "Привет");
+    assert_eq!(second.as_str(), "Привед");
+
+    // Index is in characters, not bytes
+    assert_eq!(first.first_difference(&second), Some(5));
+
+    let third = "Привет".view();
+    assert_eq!(first.first_difference(&third), None);
+}
+
+#[test]
+fn char_len_at_least() {
+    let text = "本".repeat(1000);
+
+    let view = text.view();
+    assert!(view.char_len_at_least(0));
+    assert!(view.char_len_at_least(3));
+    assert!(view.char_len_at_least(1000));
+    assert!(!view.char_len_at_least(1001));
+
+    let view = text.view_part(0, 6);
+    assert!(view.char_len_at_least(2));
+    assert!(!view.char_len_at_least(3));
+
+    let view = text.view_part(0, 0);
+    assert!(view.char_len_at_least(0));
+    assert!(!view.char_len_at_least(1));
+}
+
+#[test]
+fn char_len_eq() {
+    let text = "aé本€";
+
+    let view = text.view();
+    assert!(view.char_len_eq(4));
+    assert!(!view.char_len_eq(3));
+    assert!(!view.char_len_eq(5));
+    assert!(!view.char_len_eq(0));
+
+    let view = text.view_part(1, 1);
+    assert!(view.char_len_eq(0));
+    assert!(!view.char_len_eq(1));
+
+    let mut text = String::from("aé本€");
+    let view = text.view_part_mut(1, 6);
+    assert!(view.char_len_eq(2));
+    assert!(view.char_len_at_least(2));
+    assert!(!view.char_len_at_least(3));
+}
+
+#[test]
+fn apply() {
+    let mut text = String::from("hello world again");
+    let mut view = text.view_part_mut(6, 11);
+
+    view.apply(|s| s.make_uppercase());
+    assert_eq!(view.as_str(), "WORLD");
+
+    view.extend_right(1);
+    let len = view.apply(|s| s.len());
+    assert_eq!(len, 6);
+
+    assert_eq!(text, "hello WORLD again");
+}
+
+#[test]
+fn trim() {
+    let text = " \u{A0}Hello World\u{3000}\n ";
+
+    let mut view = text.view();
+    view.trim();
+    assert_eq!(view.as_str(), "Hello World");
+
+    let mut view = text.view_part(1, 17);
+    assert_eq!(view.as_str(), "\u{A0}Hello World\u{3000}");
+    view.trim_start();
+    assert_eq!(view.as_str(), "Hello World\u{3000}");
+    view.trim_end();
+    assert_eq!(view.as_str(), "Hello World");
+
+    let mut text = String::from("   ");
+    let mut view = text.view_mut();
+    view.trim();
+    assert!(view.is_empty());
+}
+
+#[test]
+fn rotate_window() {
+    let text = "abcdefghij";
+    let mut view = text.view_part(0, 5);
+
+    let mut windows = Vec::new();
+    while view.rotate_window_right(1) == 1 {
+        windows.push(view.as_str());
+    }
+    assert_eq!(windows, ["bcdef", "cdefg", "defgh", "efghi", "fghij"]);
+    assert_eq!(view.rotate_window_right(10), 0);
+
+    assert_eq!(view.rotate_window_left(4), 4);
+    assert_eq!(view.as_str(), "bcdef");
+    assert_eq!(view.rotate_window_left(4), 1);
+    assert_eq!(view.as_str(), "abcde");
+    assert_eq!(view.rotate_window_left(1), 0);
+}
+
+#[test]
+fn rotate_window_multibyte() {
+    let mut text = String::from("aé本€bc");
+    let mut view = text.view_part_mut(0, 3);
+    assert_eq!(view.as_str(), "aé");
+
+    assert_eq!(view.rotate_window_right(2), 2);
+    assert_eq!(view.as_str(), "本€");
+    assert_eq!(view.rotate_window_right(5), 2);
+    assert_eq!(view.as_str(), "bc");
+    assert_eq!(view.rotate_window_left(3), 3);
+    assert_eq!(view.as_str(), "é本");
+
+    // Empty view is moved as a cursor
+    view.shrink_to_left();
+    assert_eq!(view.rotate_window_right(2), 2);
+    assert_eq!((view.start(), view.as_str()), (6, ""));
+}
+
+#[test]
+fn starts_with_ends_with_window() {
+    let text = "xHellox";
+    let view = text.view_part(1, 6);
+
+    assert!(view.starts_with("Hel"));
+    assert!(view.starts_with("Hello"));
+    assert!(view.starts_with(""));
+    assert!(!view.starts_with("x"));
+    assert!(!view.starts_with("Hellox"));
+
+    assert!(view.ends_with("llo"));
+    assert!(view.ends_with(""));
+    assert!(!view.ends_with("x"));
+    assert!(!view.ends_with("xHello"));
+
+    let mut text = String::from("xHellox");
+    let mut view = text.view_part_mut(1, 6);
+    assert!(view.starts_with("Hel"));
+    assert!(!view.ends_with("lox"));
+
+    view.extend_right(1);
+    assert!(view.ends_with("lox"));
+}
+
+#[test]
+fn view_stack() {
+    use string_view::ViewStack;
+
+    let text = "Привет Мир";
+    let mut view = text.view_part(0, 0);
+    let mut stack = ViewStack::<2>::new();
+
+    view.extend_right(1);
+    stack.push(&view);
+
+    view.extend_right(2);
+    stack.push(&view);
+    assert_eq!(stack.len(), 2);
+    assert!(stack.try_push(&view).is_err());
+
+    view.extend_right_while(|_| true);
+    view.reduce_left(3);
+    assert_eq!(view.as_str(), "вет Мир");
+
+    assert!(stack.restore(&mut view));
+    assert_eq!((view.start(), view.end()), (0, 6));
+    assert_eq!(view.as_str(), "При");
+
+    assert!(stack.restore(&mut view));
+    assert_eq!((view.start(), view.end()), (0, 2));
+    assert_eq!(view.as_str(), "П");
+
+    assert!(stack.is_empty());
+    assert!(!stack.restore(&mut view));
+    assert_eq!(view.as_str(), "П");
+}
+
+#[test]
+fn view_stack_pop() {
+    use string_view::ViewStack;
+
+    let text = "Hello World";
+    let mut stack = ViewStack::<4>::default();
+
+    stack.push(&text.view_part(6, 11));
+    stack.push(&text.view_part(0, 5));
+
+    assert_eq!(stack.pop(), Some((0, 5)));
+    assert_eq!(stack.pop(), Some((6, 5)));
+    assert_eq!(stack.pop(), None);
+}
+
+#[test]
+#[should_panic]
+fn view_stack_restore_other_base() {
+    use string_view::ViewStack;
+
+    let mut stack = ViewStack::<1>::new();
+    stack.push(&"Hello World".view_part(6, 11));
+
+    let mut view = "Hello".view();
+    stack.restore(&mut view);
+}
+
+#[test]
+fn char_before_after() {
+    let text = "<aé本>";
+    let view = text.view_part(1, 7);
+    assert_eq!(view.as_str(), "aé本");
+
+    // Boundary between 'é' and '本'
+    assert_eq!(view.char_before(4).unwrap(), "é");
+    assert_eq!(view.char_after(4).unwrap(), "本");
+
+    assert_eq!(view.char_before(2).unwrap(), "a");
+    assert_eq!(view.char_after(2).unwrap(), "é");
+
+    // Window edges
+    assert!(view.char_before(1).is_none());
+    assert_eq!(view.char_after(1).unwrap(), "a");
+    assert_eq!(view.char_before(7).unwrap(), "本");
+    assert!(view.char_after(7).is_none());
+
+    // Outside of the window
+    assert!(view.char_before(0).is_none());
+    assert!(view.char_after(0).is_none());
+    assert!(view.char_before(8).is_none());
+    assert!(view.char_after(100).is_none());
+
+    // Not on a char boundary
+    assert!(view.char_before(3).is_none());
+    assert!(view.char_after(5).is_none());
+}
+
+#[test]
+fn split_lines() {
+    let text = "a\nb\n";
+
+    let lines: Vec<(usize, &str)> = text
+        .view()
+        .split_lines()
+        .map(|line| (line.start(), line.as_str()))
+        .collect();
+    assert_eq!(lines, [(0, "a"), (2, "b"), (4, "")]);
+}
+
+#[test]
+fn split_lines_matches_split() {
+    let text = "[\nfirst\r\n\nПривет\n]";
+    let view = text.view_part(1, text.len() - 1);
+
+    let lines: Vec<&str> = view.split_lines().map(|line| line.as_str()).collect();
+    let std_lines: Vec<&str> = view.as_str().split('\n').collect();
+    assert_eq!(lines, std_lines);
+    assert_eq!(lines, ["", "first\r", "", "Привет", ""]);
+
+    for line in view.split_lines() {
+        assert_eq!(&text[line.start()..line.end()], line.as_str());
+    }
+
+    let lines: Vec<&str> = "".view().split_lines().map(|line| line.as_str()).collect();
+    assert_eq!(lines, [""]);
+}
+
+#[test]
+fn reduce_to_char_range() {
+    let text = "<аб本гд>";
+    let view = || text.view_part(1, text.len() - 1);
+    assert_eq!(view().as_str(), "аб本гд");
+
+    let mut narrowed = view();
+    assert!(narrowed.reduce_to_char_range(1, 3).is_ok());
+    assert_eq!(narrowed.as_str(), "б本");
+    assert_eq!(narrowed.start(), 3);
+
+    let mut narrowed = view();
+    assert!(narrowed.reduce_to_char_range(0, 5).is_ok());
+    assert_eq!(narrowed.as_str(), "аб本гд");
+
+    let mut narrowed = view();
+    assert!(narrowed.reduce_to_char_range(5, 5).is_ok());
+    assert_eq!(narrowed.as_str(), "");
+    assert_eq!(narrowed.start(), view().end());
+
+    let mut narrowed = view();
+    assert!(narrowed.reduce_to_char_range(2, 2).is_ok());
+    assert_eq!(narrowed.as_str(), "");
+    assert_eq!(narrowed.start(), 5);
+}
+
+#[test]
+fn reduce_to_char_range_out_of_bounds() {
+    let text = "аб本гд";
+    let mut view = text.view();
+
+    assert!(view.reduce_to_char_range(3, 2).is_err());
+    assert!(view.reduce_to_char_range(2, 6).is_err());
+    assert!(view.reduce_to_char_range(6, 6).is_err());
+    assert_eq!(view.as_str(), "аб本гд");
+}
+
+#[test]
+fn views_are_ordered_by_content() {
+    let text = "pear apple fig banana apple";
+
+    let mut views: Vec<_> = text
+        .split(' ')
+        .map(|word| {
+            let start = word.as_ptr() as usize - text.as_ptr() as usize;
+            text.view_part(start, start + word.len())
+        })
+        .collect();
+    let mut words: Vec<&str> = text.split(' ').collect();
+
+    views.sort();
+    words.sort();
+
+    let sorted: Vec<&str> = views.iter().map(|view| view.as_str()).collect();
+    assert_eq!(sorted, words);
+
+    // Views into different places of base string are equal when contents are equal
+    assert_eq!(views[0], views[1]);
+    assert_ne!(views[0].start(), views[1].start());
+}
+
+#[test]
+fn view_mut_ordering() {
+    let mut first = String::from("abc");
+    let mut second = String::from("abd");
+
+    let first = first.view_mut();
+    let mut second = second.view_mut();
+
+    assert!(first < second);
+
+    second.reduce_right(1);
+    assert!(first > second);
+    assert_eq!(first.cmp(&second), "abc".cmp("ab"));
+}
+
+#[test]
+fn write_chars() {
+    let mut text = String::from("<ab>");
+    let mut view = text.view_part_mut(1, 3);
+
+    assert_eq!(view.write_chars(['H', 'i']).ok(), Some(2));
+    assert_eq!(view.as_str(), "Hi");
+
+    // Fewer characters leave the rest of the view as is
+    assert_eq!(view.write_chars(['h']).ok(), Some(1));
+    assert_eq!(view.as_str(), "hi");
+
+    // Two 1-byte characters can be replaced with a single 2-byte one
+    assert_eq!(view.write_chars(['é']).ok(), Some(2));
+    assert_eq!(view.as_str(), "é");
+    assert_eq!(text, "<é>");
+}
+
+#[test]
+fn write_chars_does_not_fit() {
+    let mut text = String::from("aé");
+    let mut view = text.view_mut();
+
+    // 'b' would split 'é' in half
+    let result = view.write_chars(['x', 'b']);
+    assert_eq!(result.map_err(|err| (err.expected, err.found)), Err((2, 1)));
+    assert_eq!(view.as_str(), "xé");
+
+    let result = view.write_chars(['a', 'b', 'c', 'd']);
+    assert_eq!(result.map_err(|err| (err.expected, err.found)), Err((2, 1)));
+
+    let result = view.write_chars(['b', '本']);
+    assert_eq!(result.map_err(|err| (err.expected, err.found)), Err((2, 3)));
+    assert_eq!(view.as_str(), "bé");
+
+    let mut text = String::from("ab");
+    let result = text.view_mut().write_chars("abc".chars());
+    assert_eq!(result.map_err(|err| (err.expected, err.found)), Err((0, 1)));
+}
+
+#[test]
+fn longest_prefix() {
+    let operators = ["<", "<=", "<<", "<<=", "="];
+
+    let text = "a <= b << c";
+    let mut view = text.view_part(2, text.len());
+    assert_eq!(view.longest_prefix(&operators), Some("<="));
+
+    view.reduce_left(5);
+    assert_eq!(view.as_str(), "<< c");
+    assert_eq!(view.longest_prefix(&operators), Some("<<"));
+
+    // Candidate longer than the view never matches
+    let text = "<<=";
+    let view = text.view_part(0, 2);
+    assert_eq!(view.longest_prefix(&operators), Some("<<"));
+
+    assert_eq!(view.longest_prefix(&[]), None);
+    assert_eq!(text.view_part(3, 3).longest_prefix(&operators), None);
+    assert_eq!(text.view_part(3, 3).longest_prefix(&["", "<"]), Some(""));
+}
+
+#[test]
+fn collect_chars_into() {
+    let text = "<аб本гд>";
+    let view = text.view_part(1, text.len() - 1);
+
+    let mut buf = ['_'; 3];
+    assert_eq!(view.collect_chars_into(&mut buf), 3);
+    assert_eq!(buf, ['а', 'б', '本']);
+
+    let mut buf = ['_'; 7];
+    assert_eq!(view.collect_chars_into(&mut buf), 5);
+    assert_eq!(buf, ['а', 'б', '本', 'г', 'д', '_', '_']);
+
+    assert_eq!(view.collect_chars_into(&mut []), 0);
+}
+
+#[test]
+fn swap_case_ascii_in_place() {
+    let mut text = String::from("Hello World");
+    let mut view = text.view_part_mut(6, 11);
+
+    view.swap_case_ascii_in_place();
+    assert_eq!(view.as_str(), "wORLD");
+
+    view.swap_case_ascii_in_place();
+    assert_eq!(view.as_str(), "World");
+    assert_eq!(text, "Hello World");
+
+    let mut text = String::from("aÄ-Zя_1ß");
+    text.view_mut().swap_case_ascii_in_place();
+    assert_eq!(text, "AÄ-zя_1ß");
+}
+
+#[test]
+fn split_n_chars() {
+    let text = "[2023-01-15]";
+    let view = text.view_part(1, text.len() - 1);
+
+    let fields: Vec<(&str, usize)> = view
+        .split_n_chars(&[4, 1, 2, 1, 2])
+        .map(|field| (field.as_str(), field.start()))
+        .collect();
+    assert_eq!(
+        fields,
+        [("2023", 1), ("-", 5), ("01", 6), ("-", 8), ("15", 9)]
+    );
+
+    let fields: Vec<&str> = "аб本гд"
+        .view()
+        .split_n_chars(&[0, 2, 1])
+        .map(|field| field.as_str())
+        .collect();
+    assert_eq!(fields, ["", "аб", "本"]);
+}
+
+#[test]
+fn split_n_chars_runs_short() {
+    let text = "[2023-01-15]";
+    let view = text.view_part(1, text.len() - 1);
+
+    // Characters outside of the view are never included
+    let mut fields = view.split_n_chars(&[4, 7, 1]);
+    assert_eq!(fields.next().unwrap().as_str(), "2023");
+    assert!(fields.next().is_none());
+    assert!(fields.next().is_none());
+
+    let mut fields = view.split_n_chars(&[11]);
+    assert!(fields.next().is_none());
+}
+
+#[test]
+fn split_once_view() {
+    let text = "[ключ => значение => ещё]";
+    let view = text.view_part(1, text.len() - 1);
+
+    let (key, value) = view.split_once_view(" => ").unwrap();
+    assert_eq!(key.as_str(), "ключ");
+    assert_eq!(value.as_str(), "значение => ещё");
+    assert_eq!(key.start(), 1);
+    assert_eq!(&text[value.start()..value.end()], "значение => ещё");
+    assert_eq!(value.end(), view.end());
+
+    // Views can be extended into the rest of base string
+    let (mut key, _) = view.split_once_view(" => ").unwrap();
+    key.extend_left(1);
+    assert_eq!(key.as_str(), "[ключ");
+
+    assert!(view.split_once_view("]").is_none());
+}
+
+#[test]
+fn split_once_view_at_edges() {
+    let text = "=";
+    let (before, after) = text.view().split_once_view("=").unwrap();
+    assert_eq!((before.as_str(), before.start()), ("", 0));
+    assert_eq!((after.as_str(), after.start()), ("", 1));
+
+    let (before, after) = "ab".view().split_once_view("").unwrap();
+    assert_eq!((before.as_str(), after.as_str()), ("", "ab"));
+}
+
+#[test]
+fn collapse() {
+    let mut text = String::from("let значение = 5;");
+    let mut view = text.view_part_mut(4, 4);
+    view.extend_right_while(char::is_alphabetic);
+
+    assert_eq!(view.collapse(), "значение");
+    assert_eq!(view.as_str(), "");
+    assert_eq!(view.start(), 4);
+
+    assert_eq!(view.collapse(), "");
+    assert_eq!(view.start(), 4);
+
+    // Collapsed view can be filled again
+    view.extend_right(8);
+    assert_eq!(view.as_str(), "значение");
+
+    view.shrink_to_empty_at_start();
+    assert_eq!((view.as_str(), view.start()), ("", 4));
+}
+
+#[test]
+fn byte() {
+    let text = "Hello World";
+    let view = text.view_part(6, 11);
+
+    let bytes: Vec<Option<u8>> = (0..6).map(|i| view.byte(i)).collect();
+    assert_eq!(
+        bytes,
+        [
+            Some(b'W'),
+            Some(b'o'),
+            Some(b'r'),
+            Some(b'l'),
+            Some(b'd'),
+            None
+        ]
+    );
+
+    let mut text = String::from("aé");
+    let mut view = text.view_mut();
+    assert_eq!(view.byte(1), Some(0xC3));
+    assert_eq!(view.byte(2), Some(0xA9));
+
+    view.reduce_right(1);
+    assert_eq!(view.byte(1), None);
+}
+
+#[test]
+fn map_window() {
+    let text = "header\n  key: значение  \nfooter";
+
+    let mut value = text
+        .view()
+        .map_window(|view| view.split_lines().nth(1).unwrap())
+        .map_window(|view| view.split_once_view(": ").unwrap().1);
+
+    value.trim();
+    assert_eq!(value.as_str(), "значение");
+    assert_eq!(&text[value.start()..value.end()], "значение");
+
+    // Mapped view keeps base string, so it can be extended past the original window
+    value.extend_right_while(|ch| ch != '\n');
+    value.extend_right(1);
+    assert_eq!(value.as_str(), "значение  \n");
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "share base string")]
+fn map_window_different_base() {
+    let other = String::from("other");
+    let _ = "text".view().map_window(|_| other.view());
+}
+
+#[test]
+fn eq_ignore_ascii_case() {
+    assert!("HELLO".view().eq_ignore_ascii_case("hello"));
+
+    let text = "[Привет World]";
+    let mut view = text.view_part(1, text.len() - 1);
+    view.reduce_left(7);
+    assert!(view.eq_ignore_ascii_case("wORLD"));
+    assert!(!view.eq_ignore_ascii_case("world]"));
+
+    let mut text = String::from("Привет");
+    let view = text.view_mut();
+    assert!(view.eq_ignore_ascii_case("Привет"));
+    assert!(!view.eq_ignore_ascii_case("привет"));
+}
+
+#[test]
+fn sub_view() {
+    let text = "fn main() { body(); }";
+    let view = text.view_part(10, text.len());
+    assert_eq!(view.as_str(), "{ body(); }");
+
+    let body = view.sub_view(2, 9);
+    assert_eq!(body.as_str(), "body();");
+    assert_eq!((body.start(), body.end()), (12, 19));
+
+    let name = body.sub_view(0, 4);
+    assert_eq!((name.as_str(), name.start()), ("body", 12));
+
+    assert_eq!(view.sub_view(0, 0).start(), 10);
+    assert_eq!(view.sub_view(11, 11).start(), text.len());
+}
+
+#[test]
+fn try_sub_view_errors() {
+    let text = "<аб>";
+    let view = text.view_part(1, text.len() - 1);
+
+    let err = view.try_sub_view(1, 4).unwrap_err();
+    assert_eq!((err.start, err.end), (1, 4));
+
+    assert!(view.try_sub_view(0, 5).is_err());
+    assert!(view.try_sub_view(4, 2).is_err());
+    assert_eq!(view.try_sub_view(2, 4).unwrap().as_str(), "б");
+}
+
+#[test]
+#[should_panic(expected = "Unable to create sub view of string view")]
+fn sub_view_panics() {
+    let text = "Hello World";
+    let _ = text.view_part(6, 11).sub_view(0, 6);
+}
+
+#[test]
+fn with_offsets() {
+    let text = "key = значение";
+    let key = text.view_part(0, 3);
+
+    let value = key.with_offsets(6, 16);
+    assert_eq!(value.as_str(), "значение");
+    assert_eq!((value.start(), value.end()), (6, text.len()));
+
+    assert_eq!((key.as_str(), key.start()), ("key", 0));
+
+    let empty = key.with_offsets(text.len(), 0);
+    assert_eq!(empty.as_str(), "");
+}
+
+#[test]
+#[should_panic(expected = "Offsets have to be in bounds")]
+fn with_offsets_not_on_boundary() {
+    let text = "key = значение";
+    let _ = text.view().with_offsets(7, 2);
+}
+
+#[test]
+#[should_panic(expected = "Offsets have to be in bounds")]
+fn with_offsets_out_of_bounds() {
+    let text = "key";
+    let _ = text.view().with_offsets(1, usize::MAX);
+}
+
+#[test]
+fn contains_only_in_window() {
+    let text = "Hello World";
+    let mut view = text.view();
+
+    assert!(view.contains('o'));
+    assert!(view.contains("World"));
+
+    view.reduce_right(6);
+    assert_eq!(view.as_str(), "Hello");
+    assert!(!view.contains("World"));
+    assert!(!view.contains('W'));
+    assert!(!view.contains("o W"));
+    assert!(view.contains('o'));
+    assert!(view.contains(""));
+
+    let mut text = String::from("ключ=значение");
+    let mut view = text.view_mut();
+    view.reduce_left(5);
+    assert!(view.contains('з'));
+    assert!(!view.contains('='));
+    assert!(!view.contains("ключ"));
+}
+
+#[test]
+fn view_into_iter() {
+    let text = "<aé本>";
+    let view = text.view_part(1, text.len() - 1);
+
+    let mut chars = Vec::new();
+    for ch in &view {
+        chars.push(ch.as_str());
+    }
+    assert_eq!(chars, ["a", "é", "本"]);
+
+    let chars: Vec<char> = view.into_iter().map(|ch| ch.char()).collect();
+    assert_eq!(chars, ['a', 'é', '本']);
+}
+
+#[test]
+fn view_mut_into_iter() {
+    let mut text = String::from("<aé本>");
+    let len = text.len();
+    let mut view = text.view_part_mut(1, len - 1);
+
+    for mut ch in &mut view {
+        let _ = ch.make_uppercase();
+    }
+    assert_eq!(view.as_str(), "AÉ本");
+
+    assert_eq!((&mut view).into_iter().count(), 3);
+    assert_eq!(text, "<AÉ本>");
+}
+
+#[test]
+fn tr_ascii_in_place() {
+    let mut text = String::from("cabbage");
+    text.view_mut().tr_ascii_in_place(b"abc", b"xyz");
+    assert_eq!(text, "zxyyxge");
+
+    // Only the window is translated, multibyte characters are untouched
+    let mut text = String::from("a-b-c été a-b");
+    let mut view = text.view_part_mut(0, 11);
+    view.tr_ascii_in_place(b"-t", b"_T");
+    assert_eq!(view.as_str(), "a_b_c éTé");
+    assert_eq!(text, "a_b_c éTé a-b");
+
+    let mut text = String::from("swap");
+    text.view_mut().tr_ascii_in_place(b"sw", b"ws");
+    assert_eq!(text, "wsap");
+}
+
+#[test]
+#[should_panic(expected = "the same length")]
+fn tr_ascii_in_place_different_lengths() {
+    let mut text = String::from("text");
+    text.view_mut().tr_ascii_in_place(b"ab", b"x");
+}
+
+#[test]
+#[should_panic(expected = "have to be ASCII")]
+fn tr_ascii_in_place_non_ascii() {
+    let mut text = String::from("text");
+    text.view_mut().tr_ascii_in_place(b"a", &[0xC3]);
+}
+
+#[test]
+fn line_count() {
+    let text = "first\nsecond\r\nthird\nfourth\n";
+    let mut view = text.view();
+    assert_eq!(view.line_count(), 4);
+
+    // Window ends in the middle of the third line
+    view.reduce_right(10);
+    assert_eq!(view.as_str(), "first\nsecond\r\nthi");
+    assert_eq!(view.line_count(), 3);
+
+    view.reduce_left(6);
+    assert_eq!(view.as_str(), "second\r\nthi");
+    assert_eq!(view.line_count(), 2);
+
+    view.reduce_right(3);
+    assert_eq!(view.line_count(), 1);
+
+    view.shrink_to_right();
+    assert_eq!(view.line_count(), 0);
+}
+
+#[test]
+fn view_as_map_key() {
+    use std::collections::{HashMap, HashSet};
+
+    let mut map: HashMap<String, u32> = HashMap::new();
+    map.insert(String::from("width"), 80);
+
+    let text = "width=80";
+    let (key, _) = text.view().split_once_view("=").unwrap();
+    assert_eq!(map.get(key.as_ref()), Some(&80));
+
+    // Views are looked up by contents via `Borrow<str>`
+    let mut set: HashSet<StringView> = HashSet::new();
+    set.insert(key);
+    assert!(set.contains("width"));
+    assert!(set.contains(&"width=".view_part(0, 5)));
+    assert!(!set.contains("width="));
+}
+
+#[test]
+fn view_mut_as_ref() {
+    fn len_of<S: AsRef<str>>(s: S) -> usize {
+        s.as_ref().len()
+    }
+
+    let mut text = String::from("Hello World");
+    let view = text.view_part_mut(6, 11);
+    assert_eq!(len_of(&view), 5);
+    assert_eq!(len_of(view), 5);
+}
+
+#[test]
+fn view_from_str() {
+    fn first_word<'a>(view: impl Into<StringView<'a>>) -> &'a str {
+        let mut view = view.into();
+        view.shrink_to_left();
+        view.extend_right_while(|ch| !ch.is_whitespace());
+        view.as_str()
+    }
+
+    let text = "Hello World";
+    assert_eq!(first_word(text), "Hello");
+    assert_eq!(first_word(text.view_part(6, 11)), "World");
+
+    let view = StringView::from(text);
+    assert_eq!((view.start(), view.end()), (0, text.len()));
+    assert_eq!(view, text.view());
+}
+
+#[test]
+fn view_mut_from_str() {
+    let mut text = String::from("Hello World");
+
+    let mut view = StringViewMut::from(text.as_mut_str());
+    assert_eq!((view.start(), view.end()), (0, 11));
+    view.as_str_mut().make_ascii_uppercase();
+
+    assert_eq!(text, "HELLO WORLD");
+}
+
+#[test]
+fn as_bytes_covers_window() {
+    let text = "[aé]";
+    let view = text.view_part(1, text.len() - 1);
+    assert_eq!(view.as_bytes(), [b'a', 0xC3, 0xA9]);
+
+    let mut text = String::from("[aé]");
+    let len = text.len();
+    let mut view = text.view_part_mut(1, len - 1);
+    assert_eq!(view.as_bytes(), [b'a', 0xC3, 0xA9]);
+
+    // SAFETY: ASCII byte is replaced with ASCII byte
+    unsafe {
+        let bytes = view.as_bytes_mut();
+        assert_eq!(bytes.len(), 3);
+        bytes[0] = b'b';
+    }
+    assert_eq!(text, "[bé]");
+}
+
+#[test]
+fn common_prefix_byte_len() {
+    let text = "fo fob";
+    let first = text.view_part(0, 2);
+    let second = text.view_part(3, 6);
+    assert_eq!(first.common_prefix_byte_len(&second), 2);
+    assert_eq!(second.common_prefix_byte_len(&first), 2);
+
+    // 'é' and 'ê' share the first byte, but it's not a character boundary
+    let first = "aéb".view();
+    let second = "aêb".view();
+    assert_eq!(first.common_prefix_byte_len(&second), 1);
+
+    let first = "本本a".view();
+    let second = "本本b".view();
+    assert_eq!(first.common_prefix_byte_len(&second), 6);
+
+    assert_eq!(first.common_prefix_byte_len(&first), first.as_str().len());
+    assert_eq!(first.common_prefix_byte_len(&"".view()), 0);
+}
+
+#[test]
+fn extend_right_to() {
+    let text = "key=value\n";
+    let mut view = text.view_part(0, 0);
+
+    assert!(view.extend_right_to('='));
+    assert_eq!(view.as_str(), "key=");
+
+    view.shrink_to_right();
+    assert!(view.extend_right_to('\n'));
+    assert_eq!((view.as_str(), view.start()), ("value\n", 4));
+
+    view.shrink_to_right();
+    assert!(!view.extend_right_to('\n'));
+    assert_eq!(view.as_str(), "");
+
+    let text = "ключ→значение";
+    let mut view = text.view_part(0, 0);
+    assert!(view.extend_right_to('→'));
+    assert_eq!(view.as_str(), "ключ→");
+}
+
+#[test]
+fn extend_left_to() {
+    let text = "key=value\n";
+    let mut view = text.view_part(text.len(), text.len());
+
+    assert!(view.extend_left_to('='));
+    assert_eq!(view.as_str(), "=value\n");
+
+    assert!(!view.extend_left_to('\n'));
+    assert_eq!(view.as_str(), "key=value\n");
+
+    let mut text = String::from("ключ→значение");
+    let len = text.len();
+    let mut view = text.view_part_mut(len, len);
+    assert!(view.extend_left_to('→'));
+    assert_eq!(view.as_str(), "→значение");
+}
+
+#[test]
+fn while_indexed_offsets() {
+    let text = "<aé本b>";
+    let mut view = text.view_part(1, 2);
+    assert_eq!(view.as_str(), "a");
+
+    let mut offsets = Vec::new();
+    view.extend_right_while_indexed(|idx, ch| {
+        offsets.push((idx, ch));
+        ch != '>'
+    });
+    assert_eq!(offsets, [(1, 'é'), (3, '本'), (6, 'b'), (7, '>')]);
+    assert_eq!(view.as_str(), "aé本b");
+
+    let mut offsets = Vec::new();
+    view.reduce_right_while_indexed(|idx, ch| {
+        offsets.push((idx, ch));
+        true
+    });
+    assert_eq!(offsets, [(6, 'b'), (3, '本'), (1, 'é'), (0, 'a')]);
+    assert_eq!((view.as_str(), view.start()), ("", 1));
+
+    let mut text = String::from("<aé本b>");
+    let len = text.len();
+    let mut view = text.view_part_mut(1, len - 1);
+
+    let mut offsets = Vec::new();
+    view.reduce_left_while_indexed(|idx, ch| {
+        offsets.push((idx, ch));
+        idx < 3
+    });
+    assert_eq!(offsets, [(0, 'a'), (1, 'é'), (3, '本')]);
+    assert_eq!((view.as_str(), view.start()), ("本b", 4));
+}
+
+#[test]
+fn extend_right_until_contains() {
+    let text = "<a>text</a><a>more</a>";
+    let mut view = text.view_part(0, 0);
+
+    assert!(view.extend_right_until_contains("</a>").is_ok());
+    assert_eq!(view.as_str(), "<a>text</a>");
+
+    // View already contains needle
+    assert!(view.extend_right_until_contains("text").is_ok());
+    assert_eq!(view.as_str(), "<a>text</a>");
+
+    assert!(view.extend_right_until_contains("more").is_ok());
+    assert_eq!(view.as_str(), "<a>text</a><a>more");
+
+    assert!(view.extend_right_until_contains("</b>").is_err());
+    assert_eq!(view.as_str(), "<a>text</a><a>more");
+
+    // Needle is searched for starting from view start
+    let mut view = text.view_part(4, 5);
+    assert!(view.extend_right_until_contains("<a>").is_ok());
+    assert_eq!(view.as_str(), "ext</a><a>");
+}
+
+#[test]
+fn saturating_extend_and_reduce() {
+    let text = "аб本гд";
+    let mut view = text.view_part(2, 4);
+    assert_eq!(view.as_str(), "б");
+
+    assert_eq!(view.extend_right_saturating(2), 2);
+    assert_eq!(view.as_str(), "б本г");
+    assert_eq!(view.extend_right_saturating(5), 1);
+    assert_eq!(view.extend_right_saturating(5), 0);
+    assert_eq!(view.as_str(), "б本гд");
+
+    assert_eq!(view.extend_left_saturating(5), 1);
+    assert_eq!(view.extend_left_saturating(5), 0);
+    assert_eq!(view.as_str(), "аб本гд");
+
+    assert_eq!(view.reduce_left_saturating(2), 2);
+    assert_eq!(view.as_str(), "本гд");
+
+    assert_eq!(view.reduce_right_saturating(1), 1);
+    assert_eq!(view.as_str(), "本г");
+
+    assert_eq!(view.reduce_right_saturating(3), 2);
+    assert_eq!(view.reduce_left_saturating(3), 0);
+    assert_eq!((view.as_str(), view.start()), ("", 4));
+}
+
+#[test]
+fn view_error_from_specific_errors() {
+    use string_view::{Side, ViewError};
+
+    fn grow_then_shrink(
+        view: &mut StringView,
+        grow: usize,
+        shrink: usize,
+    ) -> Result<(), ViewError> {
+        view.try_extend_left(grow)?;
+        view.try_reduce_right(shrink)?;
+        Ok(())
+    }
+
+    let text = "Hello World";
+
+    let mut view = text.view_part(6, 11);
+    assert!(grow_then_shrink(&mut view, 6, 6).is_ok());
+    assert_eq!(view.as_str(), "Hello");
+
+    let err = grow_then_shrink(&mut view, 1, 0).unwrap_err();
+    assert!(matches!(err, ViewError::BaseTooShort { side: Side::Left }));
+    assert_eq!(
+        std::format!("{err}"),
+        "Base String contains less characters than `n` to the left of the view"
+    );
+
+    let err = grow_then_shrink(&mut view, 0, 6).unwrap_err();
+    assert!(matches!(err, ViewError::ViewTooShort { side: Side::Right }));
+    assert_eq!(
+        std::format!("{err}"),
+        std::format!("{}", view.try_reduce_right(6).unwrap_err())
+    );
+}
+
+#[test]
+fn char_idx_at_column() {
+    let text = "> hello";
+    let view = text.view_part(2, text.len());
+
+    let indices: Vec<Option<usize>> = (0..6)
+        .map(|column| view.char_idx_at_column(column))
+        .collect();
+    assert_eq!(indices, [Some(0), Some(1), Some(2), Some(3), Some(4), None]);
+
+    assert_eq!("".view().char_idx_at_column(0), None);
+}
+
+#[test]
+#[cfg(feature = "width")]
+fn char_idx_at_column_wide_chars() {
+    let text = "a本b";
+    let view = text.view();
+
+    let indices: Vec<Option<usize>> = (0..5)
+        .map(|column| view.char_idx_at_column(column))
+        .collect();
+    assert_eq!(indices, [Some(0), Some(1), Some(1), Some(2), None]);
+
+    // Zero-width characters never take a column
+    let text = "e\u{301}x";
+    assert_eq!(text.view().char_idx_at_column(1), Some(2));
+}
+
+#[test]
+#[cfg(not(feature = "width"))]
+fn char_idx_at_column_one_column_per_char() {
+    let text = "a本b";
+    let view = text.view();
+
+    let indices: Vec<Option<usize>> = (0..4)
+        .map(|column| view.char_idx_at_column(column))
+        .collect();
+    assert_eq!(indices, [Some(0), Some(1), Some(2), None]);
+}
+
+#[test]
+fn error_side() {
+    use string_view::Side;
+
+    let text = "Hello World";
+    let mut view = text.view_part(3, 8);
+
+    assert_eq!(view.try_extend_right(4).unwrap_err().side(), Side::Right);
+    assert_eq!(view.try_extend_left(4).unwrap_err().side(), Side::Left);
+    assert_eq!(view.try_reduce_right(6).unwrap_err().side(), Side::Right);
+    assert_eq!(view.try_reduce_left(6).unwrap_err().side(), Side::Left);
+
+    let err = view.try_extend_left(4).unwrap_err();
+    assert_eq!(
+        std::format!("{err:?}"),
+        "Base String contains less characters than `n` to the left of the view"
+    );
+    let err = view.try_reduce_right(6).unwrap_err();
+    assert_eq!(
+        std::format!("{err}"),
+        "View contains less characters than `n` to the right of the view"
+    );
+}
+
+#[test]
+fn replace_with_char() {
+    let mut text = String::from("[Привет]");
+    let len = text.len();
+    let mut view = text.view_part_mut(1, len - 1);
+
+    view.replace_with_char('*');
+    assert_eq!(view.as_str(), "************");
+
+    view.fill('本');
+    assert_eq!(view.as_str(), "本本本本");
+
+    view.reduce_right(2);
+    view.fill('ж');
+    assert_eq!(text, "[жжж本本]");
+}
+
+#[test]
+fn try_replace_with_char_does_not_fit() {
+    let mut text = String::from("[abcd]");
+    let mut view = text.view_part_mut(1, 5);
+
+    let err = view.try_replace_with_char('本').unwrap_err();
+    assert_eq!((err.view_len, err.char_len), (4, 3));
+    assert_eq!(view.as_str(), "abcd");
+
+    assert!(view.try_replace_with_char('ж').is_ok());
+    assert_eq!(text, "[жж]");
+}
+
+#[test]
+#[should_panic(expected = "Unable to replace string view with character")]
+fn fill_does_not_fit() {
+    let mut text = String::from("abc");
+    text.view_mut().fill('ж');
+}
+
+#[test]
+fn for_each_word_mut_title_case() {
+    let mut text = String::from("the quick  brown\nfox jumps");
+    let mut view = text.view_part_mut(0, 20);
+
+    view.for_each_word_mut(|word| {
+        if let Some(first) = word.get_mut(..1) {
+            first.make_ascii_uppercase();
+        }
+    });
+    assert_eq!(text, "The Quick  Brown\nFox jumps");
+
+    let mut text = String::from("  ");
+    text.view_mut().for_each_word_mut(|_| unreachable!());
+}
+
+#[test]
+fn escape_debug_len() {
+    let text = "a\tb";
+    assert_eq!(text.view().escape_debug_len(), 4);
+
+    for text in ["", "Hello", "\"quoted\"\n", "Привет\0", "\u{7f}\\"] {
+        let escaped = text
+            .chars()
+            .flat_map(char::escape_debug)
+            .collect::<String>();
+        assert_eq!(text.view().escape_debug_len(), escaped.len());
+    }
+
+    let mut text = String::from("\r\n|\t");
+    let view = text.view_part_mut(0, 2);
+    assert_eq!(view.escape_debug_len(), 4);
+}
+
+#[test]
+fn replace_in_place_respects_window() {
+    let mut text = String::from("Привет, Мир!");
+    let mut view = text.view_mut();
+
+    view.reduce_left(8);
+    view.reduce_right(1);
+    assert_eq!(view.as_str(), "Мир");
+
+    let err = view.try_replace_in_place("World").unwrap_err();
+    assert_eq!((err.expected, err.found), (6, 5));
+    assert_eq!(view.as_str(), "Мир");
+
+    view.replace_in_place("Worlds");
+    assert_eq!(text, "Привет, Worlds!");
+}
+
+#[test]
+#[should_panic(expected = "Unable to replace string slice in-place")]
+fn replace_in_place_different_length() {
+    let mut text = String::from("Hello World");
+    text.view_part_mut(0, 5).replace_in_place("Hi");
+}
+
+#[test]
+fn make_case_only_inside_window() {
+    let mut text = String::from("abc Привет ßtraße xyz");
+    let mut view = text.view_mut();
+    view.reduce_left(4);
+    view.reduce_right(4);
+
+    view.make_uppercase();
+    // `ß` uppercases to `SS` and is left as is
+    assert_eq!(view.as_str(), "ПРИВЕТ ßTRAßE");
+    assert_eq!(text, "abc ПРИВЕТ ßTRAßE xyz");
+
+    let mut view = text.view_mut();
+    view.reduce_left(4);
+    view.reduce_right(4);
+
+    view.make_lowercase();
+    assert_eq!(text, "abc привет ßtraße xyz");
+
+    let mut text = String::from("ab Ёж cd");
+    let mut view = text.view_part_mut(2, 8);
+
+    view.make_ascii_uppercase();
+    assert_eq!(text, "ab Ёж cd");
+
+    let mut view = text.view_part_mut(0, 2);
+    view.make_ascii_uppercase();
+    assert_eq!(view.as_str(), "AB");
+
+    view.make_ascii_lowercase();
+    assert_eq!(text, "ab Ёж cd");
+}
+
+#[test]
+fn reborrow_keeps_original_window() {
+    fn next_word(view: &mut StringViewMut) {
+        view.extend_right_while(char::is_whitespace);
+        view.reduce_left(view.as_str().chars().count());
+        view.extend_right_while(|ch| !ch.is_whitespace());
+    }
+
+    let mut text = String::from("Привет big мир");
+    let mut view = text.view_part_mut(0, 12);
+
+    let mut reborrow = view.reborrow();
+    assert_eq!(reborrow.as_str(), "Привет");
+
+    next_word(&mut reborrow);
+    assert_eq!(reborrow.as_str(), "big");
+    reborrow.make_uppercase();
+
+    next_word(&mut reborrow);
+    assert_eq!(reborrow.as_str(), "мир");
+
+    assert_eq!(view.as_str(), "Привет");
+    assert_eq!(view.start(), 0);
+
+    view.reduce_right(2);
+    assert_eq!(text, "Привет BIG мир");
+}
+
+#[test]
+fn for_each_char_offset_matches_char_indices() {
+    let text = "aé本😀 Hello Привет";
+    let mut view = text.view();
+    view.reduce_left(2);
+    view.reduce_right(3);
+
+    let mut offsets = Vec::new();
+    view.for_each_char_offset(|offset, ch| offsets.push((offset, ch.as_str())));
+
+    let expected = view
+        .char_indices_in_place()
+        .map(|(offset, ch)| (offset, ch.as_str()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(offsets, expected);
+    assert_eq!(offsets.first(), Some(&(3, "本")));
+
+    text.view_part(3, 3)
+        .for_each_char_offset(|_, _| unreachable!());
+}
+
+#[test]
+fn trim_matches_mut_narrows_window() {
+    let mut text = String::from("--hi--");
+    let mut view = text.view_mut();
+
+    view.trim_matches_mut(|ch| ch == '-');
+    assert_eq!(view.as_str(), "hi");
+    assert_eq!(view.start(), 2);
+
+    view.extend_left(2);
+    view.extend_right(1);
+    assert_eq!(view.as_str(), "--hi-");
+
+    view.trim_matches_mut(|ch| ch == '-' || ch == 'h');
+    assert_eq!(view.as_str(), "i");
+
+    view.trim_matches_mut(|_| true);
+    assert_eq!(view.as_str(), "");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn view_into_string() {
+    let text = "Привет, Мир!";
+    let mut view = text.view();
+    view.reduce_left(8);
+    view.reduce_right(1);
+
+    assert_eq!(view.to_string(), "Мир");
+    assert_eq!(String::from(text.view_part(0, 12)), "Привет");
+
+    let owned: String = view.into();
+    assert_eq!(owned, "Мир");
+
+    assert_eq!(text.view_part(4, 4).into_owned(), "");
+}
+
+#[test]
+fn chars_eq_by() {
+    let any_digit = |a: char, b: char| a == b || a.is_ascii_digit() && b.is_ascii_digit();
+
+    let text = "a1b";
+    assert!(text.view().chars_eq_by("a9b", any_digit));
+    assert!(!text.view().chars_eq_by("a9c", any_digit));
+    assert!(!text.view().chars_eq_by("a9b0", any_digit));
+    assert!(!text.view().chars_eq_by("a9", any_digit));
+
+    let text = "Привет";
+    let view = text.view_part(0, 4);
+    assert!(view.chars_eq_by("пр", |a, b| a.to_lowercase().eq(b.to_lowercase())));
+
+    assert!(text.view_part(0, 0).chars_eq_by("", |_, _| false));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn serialize_views() {
+    let text = "key: \"Привет\"\n";
+    let mut view = text.view();
+    view.reduce_left(5);
+
+    let json = serde_json::to_string(&view).unwrap();
+    assert_eq!(json, r#""\"Привет\"\n""#);
+
+    let parsed: String = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, view.as_str());
+
+    let mut text = String::from("a\tb");
+    let view = text.view_part_mut(1, 3);
+
+    let json = serde_json::to_string(&view).unwrap();
+    assert_eq!(json, r#""\tb""#);
+    assert_eq!(serde_json::from_str::<String>(&json).unwrap(), "\tb");
+}